@@ -60,7 +60,6 @@ impl Binary {
         Ok(Binary { subtype, bytes })
     }
 
-    #[cfg(feature = "serde")]
     pub(crate) fn from_extended_doc(doc: &crate::Document) -> Option<Self> {
         use std::convert::TryFrom;
 
@@ -98,4 +97,45 @@ impl Binary {
             subtype: self.subtype,
         }
     }
+
+    /// Whether this value has the [`BinarySubtype::Encrypted`] subtype used for client-side
+    /// field level encryption.
+    pub fn is_encrypted(&self) -> bool {
+        self.subtype == BinarySubtype::Encrypted
+    }
+
+    /// Returns the algorithm used to produce this value if it is a client-side field level
+    /// encryption ciphertext, as indicated by the first byte of the payload. Returns [`None`] if
+    /// the subtype is not [`BinarySubtype::Encrypted`] or the payload is not a ciphertext produced
+    /// by a known algorithm.
+    ///
+    /// This only inspects the payload; it does not decrypt or otherwise validate it.
+    ///
+    /// ```rust
+    /// # use bson::{binary::EncryptionAlgorithm, spec::BinarySubtype, Binary};
+    /// let binary = Binary { subtype: BinarySubtype::Encrypted, bytes: vec![2, 0, 0, 0] };
+    /// assert_eq!(binary.encryption_algorithm(), Some(EncryptionAlgorithm::Random));
+    /// ```
+    pub fn encryption_algorithm(&self) -> Option<EncryptionAlgorithm> {
+        if !self.is_encrypted() {
+            return None;
+        }
+        match self.bytes.first()? {
+            1 => Some(EncryptionAlgorithm::Deterministic),
+            2 => Some(EncryptionAlgorithm::Random),
+            _ => None,
+        }
+    }
+}
+
+/// The algorithms used to encrypt values for client-side field level encryption, as identified by
+/// the first byte of a [`BinarySubtype::Encrypted`] payload.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum EncryptionAlgorithm {
+    /// `AEAD_AES_256_CBC_HMAC_SHA_512-Deterministic`
+    Deterministic,
+
+    /// `AEAD_AES_256_CBC_HMAC_SHA_512-Random`
+    Random,
 }
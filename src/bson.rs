@@ -29,7 +29,13 @@ use std::{
 };
 
 pub use crate::document::Document;
-use crate::{oid, raw::CString, spec::ElementType, Binary, Decimal128};
+use crate::{
+    oid,
+    raw::CString,
+    spec::{BinarySubtype, ElementType},
+    Binary,
+    Decimal128,
+};
 
 /// Possible BSON value types.
 #[derive(Clone, Default, PartialEq)]
@@ -82,8 +88,12 @@ pub enum Bson {
 /// Alias for `Vec<Bson>`.
 pub type Array = Vec<Bson>;
 
+/// Values of different BSON types always hash differently. Doubles are hashed by their bit
+/// pattern, except that `+0.0` and `-0.0` hash the same and all `NaN` values hash the same, even
+/// though `NaN` never compares equal to itself.
 impl Hash for Bson {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
         match self {
             Bson::Double(double) => {
                 if *double == 0.0_f64 {
@@ -91,6 +101,10 @@ impl Hash for Bson {
                     // compare equal but have different bits. We use the +0 hash
                     // for both so that hash(+0) == hash(-0).
                     0.0_f64.to_bits().hash(state);
+                } else if double.is_nan() {
+                    // NaN has many bit representations; use a single canonical one so that all
+                    // NaNs hash consistently.
+                    f64::NAN.to_bits().hash(state);
                 } else {
                     double.to_bits().hash(state);
                 }
@@ -278,6 +292,22 @@ impl From<Document> for Bson {
     }
 }
 
+/// Extracts the [`Document`] from a [`Bson::Document`], returning a
+/// [`ValueAccess`](crate::error::ErrorKind::ValueAccess) error for any other variant.
+impl TryFrom<Bson> for Document {
+    type Error = crate::error::Error;
+
+    fn try_from(bson: Bson) -> crate::error::Result<Document> {
+        match bson {
+            Bson::Document(doc) => Ok(doc),
+            other => Err(crate::error::Error::value_access_unexpected_type(
+                other.element_type(),
+                ElementType::EmbeddedDocument,
+            )),
+        }
+    }
+}
+
 impl From<bool> for Bson {
     fn from(a: bool) -> Bson {
         Bson::Boolean(a)
@@ -344,6 +374,64 @@ where
     }
 }
 
+/// Conversion of a borrowed value into a [`Bson`] by cloning it.
+///
+/// This is implemented for every type that can be converted [`Into`] a [`Bson`], as well as for
+/// `str` and for slices of such types, and allows writing `value.to_bson()` in place of
+/// `Bson::from(value.clone())`.
+///
+/// Slices are converted to a [`Bson::Array`], except for byte slices, which are converted to a
+/// [`Bson::Binary`] with the [`Generic`](BinarySubtype::Generic) subtype.
+///
+/// ```
+/// use bson::{spec::BinarySubtype, Binary, Bson, ToBson};
+///
+/// assert_eq!(42.to_bson(), Bson::Int32(42));
+/// assert_eq!("hello".to_bson(), Bson::String("hello".to_string()));
+/// assert_eq!([true, false][..].to_bson(), Bson::Array(vec![true.into(), false.into()]));
+/// assert_eq!(
+///     b"\x01\x02"[..].to_bson(),
+///     Bson::Binary(Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2] })
+/// );
+/// ```
+pub trait ToBson {
+    /// Converts this value into a [`Bson`].
+    fn to_bson(&self) -> Bson;
+}
+
+impl<T> ToBson for T
+where
+    T: Clone + Into<Bson>,
+{
+    fn to_bson(&self) -> Bson {
+        self.clone().into()
+    }
+}
+
+impl ToBson for str {
+    fn to_bson(&self) -> Bson {
+        Bson::from(self)
+    }
+}
+
+impl<T> ToBson for [T]
+where
+    T: Clone + Into<Bson>,
+{
+    fn to_bson(&self) -> Bson {
+        Bson::from(self)
+    }
+}
+
+impl ToBson for [u8] {
+    fn to_bson(&self) -> Bson {
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: self.to_vec(),
+        })
+    }
+}
+
 impl<T: Into<Bson>> ::std::iter::FromIterator<T> for Bson {
     /// # Examples
     ///
@@ -476,6 +564,407 @@ impl Bson {
         }
     }
 
+    /// Get the name of this value's variant (e.g. `"Double"` or `"Document"`), for use in error
+    /// messages and logging.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// assert_eq!(Bson::Int32(1).type_name(), "Int32");
+    /// assert_eq!(bson!({ "x": 1 }).type_name(), "Document");
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        self.element_type().type_name()
+    }
+
+    /// Get the number of bytes this value's payload occupies when encoded as BSON, excluding the
+    /// element type tag and key.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// assert_eq!(Bson::Int32(1).size_bytes(), 4);
+    /// assert_eq!(Bson::from("abc").size_bytes(), 8);
+    /// assert_eq!(bson!({ "x": 1 }).size_bytes(), 12);
+    /// ```
+    pub fn size_bytes(&self) -> usize {
+        fn string_size(s: &str) -> usize {
+            4 + s.len() + 1
+        }
+
+        fn document_size(doc: &Document) -> usize {
+            4 + doc
+                .iter()
+                .map(|(key, value)| value.size_in_document(key))
+                .sum::<usize>()
+                + 1
+        }
+
+        match self {
+            Bson::Double(..) | Bson::Int64(..) | Bson::DateTime(..) | Bson::Timestamp(..) => 8,
+            Bson::Int32(..) => 4,
+            Bson::Boolean(..) => 1,
+            Bson::Null | Bson::Undefined | Bson::MaxKey | Bson::MinKey => 0,
+            Bson::ObjectId(..) => 12,
+            Bson::Decimal128(..) => 16,
+            Bson::String(s) | Bson::JavaScriptCode(s) | Bson::Symbol(s) => string_size(s),
+            Bson::Binary(binary) => {
+                let old_len = match binary.subtype {
+                    BinarySubtype::BinaryOld => 4,
+                    _ => 0,
+                };
+                4 + 1 + old_len + binary.bytes.len()
+            }
+            Bson::RegularExpression(regex) => {
+                regex.pattern.as_str().len() + 1 + regex.options.as_str().len() + 1
+            }
+            Bson::DbPointer(DbPointer { namespace, .. }) => string_size(namespace) + 12,
+            Bson::Document(doc) => document_size(doc),
+            Bson::Array(array) => {
+                4 + array
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| value.size_in_document(&i.to_string()))
+                    .sum::<usize>()
+                    + 1
+            }
+            Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { code, scope }) => {
+                4 + string_size(code) + document_size(scope)
+            }
+        }
+    }
+
+    /// Get the number of bytes this value occupies when encoded as an element of a BSON document
+    /// under the given key, i.e. the element type tag, the null-terminated key, and the payload
+    /// (see [`Bson::size_bytes`]).
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let doc = doc! { "a": 1, "b": "two" };
+    /// let elements: usize = doc.iter().map(|(k, v)| v.size_in_document(k)).sum();
+    /// // the document's length prefix and trailing null byte account for the remaining 5 bytes
+    /// assert_eq!(elements + 5, doc.encode_to_vec().unwrap().len());
+    /// ```
+    pub fn size_in_document(&self, key: &str) -> usize {
+        1 + key.len() + 1 + self.size_bytes()
+    }
+
+    /// Get a quick estimate of the number of bytes this value's payload occupies when encoded as
+    /// BSON, without traversing nested documents and arrays.
+    ///
+    /// For values that do not contain a document or array, this is exact and equal to
+    /// [`Bson::size_bytes`]. For documents, arrays, and code with scope values, only the top level
+    /// is measured and any nested document or array is counted as if it were empty, so the result
+    /// is a lower bound on [`Bson::size_bytes`] that is exact when there is no such nesting. This
+    /// takes time proportional to the number of top-level elements rather than to the total
+    /// number of values.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// assert_eq!(Bson::from("abc").approximate_size_bytes(), 8);
+    /// assert_eq!(bson!({ "x": 1 }).approximate_size_bytes(), 12);
+    ///
+    /// let nested = bson!({ "x": { "y": 1 } });
+    /// assert_eq!(nested.approximate_size_bytes(), 13);
+    /// assert_eq!(nested.size_bytes(), 20);
+    /// ```
+    pub fn approximate_size_bytes(&self) -> usize {
+        fn shallow_size(value: &Bson) -> usize {
+            match value {
+                Bson::Document(..) | Bson::Array(..) => 4 + 1,
+                Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { code, .. }) => {
+                    4 + 4 + code.len() + 1 + 4 + 1
+                }
+                other => other.size_bytes(),
+            }
+        }
+
+        fn document_size<'a>(elements: impl Iterator<Item = (usize, &'a Bson)>) -> usize {
+            4 + elements
+                .map(|(key_len, value)| 1 + key_len + 1 + shallow_size(value))
+                .sum::<usize>()
+                + 1
+        }
+
+        fn index_len(mut i: usize) -> usize {
+            let mut len = 1;
+            while i >= 10 {
+                i /= 10;
+                len += 1;
+            }
+            len
+        }
+
+        match self {
+            Bson::Document(doc) => document_size(doc.iter().map(|(key, value)| (key.len(), value))),
+            Bson::Array(array) => document_size(
+                array
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| (index_len(i), value)),
+            ),
+            Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { code, scope }) => {
+                4 + 4
+                    + code.len()
+                    + 1
+                    + document_size(scope.iter().map(|(key, value)| (key.len(), value)))
+            }
+            other => other.size_bytes(),
+        }
+    }
+
+    /// Returns this value with the keys of every document it contains sorted in ascending order,
+    /// including documents nested within other documents, arrays, and code with scope values.
+    ///
+    /// Although [`Document`] equality ignores key order, the encoded bytes of a document do not;
+    /// sorting keys first ensures that equal values encode identically, which is useful for
+    /// snapshot testing or hashing encoded bytes.
+    ///
+    /// ```
+    /// use bson::{bson, doc};
+    ///
+    /// let sorted = bson!({ "b": 1, "a": { "d": 2, "c": 3 } }).sort_keys();
+    /// let doc = sorted.as_document().unwrap();
+    /// assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b"]);
+    /// assert_eq!(doc.get_document("a")?.keys().collect::<Vec<_>>(), ["c", "d"]);
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn sort_keys(self) -> Bson {
+        fn sort_document(doc: Document) -> Document {
+            let mut entries: Vec<_> = doc
+                .into_iter()
+                .map(|(key, value)| (key, value.sort_keys()))
+                .collect();
+            entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            entries.into_iter().collect()
+        }
+
+        match self {
+            Bson::Document(doc) => Bson::Document(sort_document(doc)),
+            Bson::Array(array) => Bson::Array(array.into_iter().map(Bson::sort_keys).collect()),
+            Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { code, scope }) => {
+                Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                    code,
+                    scope: sort_document(scope),
+                })
+            }
+            other => other,
+        }
+    }
+
+    /// Returns this value with every value that satisfies `predicate` replaced by a clone of
+    /// `replacement`, including values nested within documents, arrays, and code with scope
+    /// values.
+    ///
+    /// Values are tested before their contents, so the contents of a replaced document or array
+    /// are not visited, and replacements are never themselves tested.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// let value = bson!({ "name": "alice", "tags": ["a", 1], "meta": { "owner": "bob" } });
+    /// let anonymized = value.replace_all(|v| matches!(v, Bson::String(_)), Bson::Null);
+    /// assert_eq!(
+    ///     anonymized,
+    ///     bson!({ "name": null, "tags": [null, 1], "meta": { "owner": null } })
+    /// );
+    /// ```
+    pub fn replace_all<F: Fn(&Bson) -> bool>(self, predicate: F, replacement: Bson) -> Bson {
+        fn replace<F: Fn(&Bson) -> bool>(value: Bson, predicate: &F, replacement: &Bson) -> Bson {
+            if predicate(&value) {
+                return replacement.clone();
+            }
+            match value {
+                Bson::Document(doc) => {
+                    Bson::Document(replace_in_document(doc, predicate, replacement))
+                }
+                Bson::Array(array) => Bson::Array(
+                    array
+                        .into_iter()
+                        .map(|value| replace(value, predicate, replacement))
+                        .collect(),
+                ),
+                Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { code, scope }) => {
+                    Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                        code,
+                        scope: replace_in_document(scope, predicate, replacement),
+                    })
+                }
+                other => other,
+            }
+        }
+
+        fn replace_in_document<F: Fn(&Bson) -> bool>(
+            doc: Document,
+            predicate: &F,
+            replacement: &Bson,
+        ) -> Document {
+            doc.into_iter()
+                .map(|(key, value)| (key, replace(value, predicate, replacement)))
+                .collect()
+        }
+
+        replace(self, &predicate, &replacement)
+    }
+
+    /// Calls `f` on this value and then on every value nested within it, including values within
+    /// documents, arrays, and code with scope values, in pre-order.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// let value = bson!({ "a": "x", "b": [1, "y"] });
+    /// let mut strings = Vec::new();
+    /// value.visit(&mut |v| {
+    ///     if let Bson::String(s) = v {
+    ///         strings.push(s.clone());
+    ///     }
+    /// });
+    /// assert_eq!(strings, ["x", "y"]);
+    /// ```
+    pub fn visit<F: FnMut(&Bson)>(&self, f: &mut F) {
+        f(self);
+        match self {
+            Bson::Document(doc)
+            | Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { scope: doc, .. }) => {
+                doc.values().for_each(|value| value.visit(f))
+            }
+            Bson::Array(array) => array.iter().for_each(|value| value.visit(f)),
+            _ => {}
+        }
+    }
+
+    /// Calls `f` on this value and then on every value nested within it, including values within
+    /// documents, arrays, and code with scope values, in pre-order.
+    ///
+    /// Because `f` is called on a value before its contents, any contents that `f` adds to a value
+    /// are also visited.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// let mut value = bson!({ "a": 1, "b": [2, "x"] });
+    /// value.visit_mut(&mut |v| {
+    ///     if let Bson::Int32(i) = v {
+    ///         *i *= 10;
+    ///     }
+    /// });
+    /// assert_eq!(value, bson!({ "a": 10, "b": [20, "x"] }));
+    /// ```
+    pub fn visit_mut<F: FnMut(&mut Bson)>(&mut self, f: &mut F) {
+        f(self);
+        match self {
+            Bson::Document(doc)
+            | Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { scope: doc, .. }) => {
+                doc.values_mut().for_each(|value| value.visit_mut(f))
+            }
+            Bson::Array(array) => array.iter_mut().for_each(|value| value.visit_mut(f)),
+            _ => {}
+        }
+    }
+
+    /// Returns this value with every [`Int32`](Bson::Int32) converted to an
+    /// [`Int64`](Bson::Int64), including values nested within documents, arrays, and code with
+    /// scope values.
+    ///
+    /// ```
+    /// use bson::bson;
+    ///
+    /// let value = bson!({ "a": 1, "b": [2_i64, "x"] });
+    /// assert_eq!(value.normalize_to_int64(), bson!({ "a": 1_i64, "b": [2_i64, "x"] }));
+    /// ```
+    pub fn normalize_to_int64(mut self) -> Bson {
+        self.visit_mut(&mut |value| {
+            if let Bson::Int32(i) = *value {
+                *value = Bson::Int64(i.into());
+            }
+        });
+        self
+    }
+
+    /// Returns this value with every [`Int64`](Bson::Int64) that fits in an `i32` converted to an
+    /// [`Int32`](Bson::Int32), including values nested within documents, arrays, and code with
+    /// scope values. Other values, including [`Double`](Bson::Double)s, are left unchanged.
+    ///
+    /// ```
+    /// use bson::bson;
+    ///
+    /// let value = bson!({ "a": 1_i64, "b": [i64::MAX, "x"] });
+    /// assert_eq!(value.normalize_to_smallest_int(), bson!({ "a": 1, "b": [i64::MAX, "x"] }));
+    /// ```
+    pub fn normalize_to_smallest_int(mut self) -> Bson {
+        self.visit_mut(&mut |value| {
+            if let Bson::Int64(i) = *value {
+                if let Ok(i) = i32::try_from(i) {
+                    *value = Bson::Int32(i);
+                }
+            }
+        });
+        self
+    }
+
+    /// Flattens a [`Bson::Document`] into a document with no nested documents, whose keys are the
+    /// paths to the non-document values joined by `separator`.
+    ///
+    /// Empty nested documents are kept as values. Arrays are also kept as values, but an error is
+    /// returned if an array contains a document, since its fields cannot be represented by a key
+    /// path. An error is also returned if this value is not a document. If the same key is
+    /// produced more than once, e.g. for `{ "a.b": 1, "a": { "b": 2 } }`, the last value wins.
+    ///
+    /// ```
+    /// use bson::{bson, doc};
+    ///
+    /// let value = bson!({ "a": { "b": { "c": 1 } }, "d": [2, 3] });
+    /// assert_eq!(value.flatten_to_document(".")?, doc! { "a.b.c": 1, "d": [2, 3] });
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn flatten_to_document(self, separator: &str) -> crate::error::Result<Document> {
+        fn contains_document(array: &Array) -> bool {
+            array.iter().any(|value| match value {
+                Bson::Document(..) => true,
+                Bson::Array(array) => contains_document(array),
+                _ => false,
+            })
+        }
+
+        fn flatten_into(
+            out: &mut Document,
+            prefix: Option<&str>,
+            doc: Document,
+            separator: &str,
+        ) -> crate::error::Result<()> {
+            for (key, value) in doc {
+                let key = match prefix {
+                    Some(prefix) => format!("{}{}{}", prefix, separator, key),
+                    None => key,
+                };
+                match value {
+                    Bson::Document(doc) if !doc.is_empty() => {
+                        flatten_into(out, Some(&key), doc, separator)?
+                    }
+                    Bson::Array(array) if contains_document(&array) => {
+                        return Err(crate::error::Error::value_access_unexpected_type(
+                            ElementType::Array,
+                            ElementType::EmbeddedDocument,
+                        )
+                        .with_key(key)
+                        .with_message("arrays containing documents cannot be flattened"));
+                    }
+                    value => {
+                        out.insert(key, value);
+                    }
+                }
+            }
+            Ok(())
+        }
+
+        let mut out = Document::new();
+        flatten_into(&mut out, None, Document::try_from(self)?, separator)?;
+        Ok(out)
+    }
+
     /// Converts to extended format.
     /// This function mainly used for [extended JSON format](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
     // TODO RUST-426: Investigate either removing this from the serde implementation or unifying
@@ -590,7 +1079,6 @@ impl Bson {
         }
     }
 
-    #[cfg(feature = "serde")]
     pub(crate) fn from_extended_document(doc: Document) -> Bson {
         if doc.len() > 2 {
             return Bson::Document(doc);
@@ -782,6 +1270,41 @@ impl Bson {
         )
     }
 
+    /// Converts any documents within this value that are
+    /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/)
+    /// wrappers, e.g. `{ "$oid": "..." }`, into the BSON values they represent, searching through
+    /// nested documents and arrays.
+    ///
+    /// A document is only treated as a wrapper if all of its keys begin with `$` and it is valid
+    /// canonical or relaxed extended JSON; anything else is left as a document.
+    ///
+    /// ```
+    /// # use bson::{doc, oid::ObjectId, Bson};
+    /// let oid = ObjectId::parse_str("507f1f77bcf86cd799439011")?;
+    /// let doc = doc! { "_id": { "$oid": oid.to_hex() }, "n": { "$numberLong": "5" } };
+    /// assert_eq!(Bson::from(doc).canonicalize(), Bson::from(doc! { "_id": oid, "n": 5i64 }));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonicalize(self) -> Bson {
+        match self {
+            Bson::Document(doc) => {
+                // nested wrappers such as { "$date": { "$numberLong": "..." } } are folded from the
+                // inside out
+                let doc: Document = doc
+                    .into_iter()
+                    .map(|(key, value)| (key, value.canonicalize()))
+                    .collect();
+                if !doc.is_empty() && doc.keys().all(|key| key.starts_with('$')) {
+                    Bson::from_extended_document(doc)
+                } else {
+                    Bson::Document(doc)
+                }
+            }
+            Bson::Array(array) => Bson::Array(array.into_iter().map(Bson::canonicalize).collect()),
+            other => other,
+        }
+    }
+
     /// Method for converting a given [`Bson`] value to a [`serde::de::Unexpected`] for error
     /// reporting.
     #[cfg(feature = "serde")]
@@ -859,6 +1382,62 @@ impl Bson {
         }
     }
 
+    /// If `self` is [`Array`](Bson::Array), return the result of calling `f` on each of its
+    /// elements. Returns [`None`] otherwise, or if `f` returns [`None`] for any element.
+    ///
+    /// ```
+    /// use bson::{bson, Bson};
+    ///
+    /// let value = bson!([1, 2, 3]);
+    /// assert_eq!(value.as_array_of(Bson::as_i32), Some(vec![1, 2, 3]));
+    /// assert_eq!(value.as_array_of(Bson::as_str), None);
+    /// ```
+    pub fn as_array_of<'a, T, F: FnMut(&'a Bson) -> Option<T>>(&'a self, f: F) -> Option<Vec<T>> {
+        self.as_array()?.iter().map(f).collect()
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`String`](Bson::String)s, return their values. Returns [`None`] otherwise.
+    pub fn as_array_of_str(&self) -> Option<Vec<&str>> {
+        self.as_array_of(Bson::as_str)
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`Int32`](Bson::Int32)s, return their values. Returns [`None`] otherwise.
+    pub fn as_array_of_i32(&self) -> Option<Vec<i32>> {
+        self.as_array_of(Bson::as_i32)
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`Int64`](Bson::Int64)s, return their values. Returns [`None`] otherwise.
+    pub fn as_array_of_i64(&self) -> Option<Vec<i64>> {
+        self.as_array_of(Bson::as_i64)
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`Double`](Bson::Double)s, return their values. Returns [`None`] otherwise.
+    pub fn as_array_of_f64(&self) -> Option<Vec<f64>> {
+        self.as_array_of(Bson::as_f64)
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`Boolean`](Bson::Boolean)s, return their values. Returns [`None`] otherwise.
+    pub fn as_array_of_bool(&self) -> Option<Vec<bool>> {
+        self.as_array_of(Bson::as_bool)
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`Document`](Bson::Document)s, return references to them. Returns [`None`] otherwise.
+    pub fn as_array_of_documents(&self) -> Option<Vec<&Document>> {
+        self.as_array_of(Bson::as_document)
+    }
+
+    /// If `self` is [`Array`](Bson::Array) and all of its elements are
+    /// [`ObjectId`](Bson::ObjectId)s, return their values. Returns [`None`] otherwise.
+    pub fn as_array_of_object_ids(&self) -> Option<Vec<oid::ObjectId>> {
+        self.as_array_of(Bson::as_object_id)
+    }
+
     /// If `self` is [`Document`](Bson::Document), return its value. Returns [`None`] otherwise.
     pub fn as_document(&self) -> Option<&Document> {
         match *self {
@@ -959,6 +1538,24 @@ impl Bson {
         }
     }
 
+    /// If `self` is [`Decimal128`](Bson::Decimal128), return its value. Returns [`None`]
+    /// otherwise.
+    pub fn as_decimal128(&self) -> Option<&Decimal128> {
+        match *self {
+            Bson::Decimal128(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// If `self` is [`Decimal128`](Bson::Decimal128), return a mutable reference to its value.
+    /// Returns [`None`] otherwise.
+    pub fn as_decimal128_mut(&mut self) -> Option<&mut Decimal128> {
+        match *self {
+            Bson::Decimal128(ref mut v) => Some(v),
+            _ => None,
+        }
+    }
+
     /// If `self` is [`Null`](Bson::Null), return `()`. Returns [`None`] otherwise.
     pub fn as_null(&self) -> Option<()> {
         match *self {
@@ -967,6 +1564,32 @@ impl Bson {
         }
     }
 
+    /// Returns [`None`] if `self` is [`Null`](Bson::Null), and `self` otherwise. This allows a
+    /// null value to be treated the same as a missing one.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let doc = doc! { "a": null, "b": 0 };
+    /// assert_eq!(doc.get("a").and_then(Bson::as_non_null), None);
+    /// assert_eq!(doc.get("b").and_then(Bson::as_non_null), Some(&Bson::Int32(0)));
+    /// ```
+    pub fn as_non_null(&self) -> Option<&Bson> {
+        match *self {
+            Bson::Null => None,
+            _ => Some(self),
+        }
+    }
+
+    /// Returns [`None`] if `self` is [`Null`](Bson::Null), and `self` otherwise. See
+    /// [`Bson::as_non_null`] for more details.
+    pub fn into_non_null(self) -> Option<Bson> {
+        match self {
+            Bson::Null => None,
+            _ => Some(self),
+        }
+    }
+
     /// If `self` is [`DbPointer`](Bson::DbPointer), return its value.  Returns [`None`] otherwise.
     pub fn as_db_pointer(&self) -> Option<&DbPointer> {
         match self {
@@ -974,6 +1597,32 @@ impl Bson {
             _ => None,
         }
     }
+
+    /// If `self` is [`String`](Bson::String), return its value as a `&str`. Returns `default`
+    /// otherwise.
+    pub fn unwrap_str_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.as_str().unwrap_or(default)
+    }
+
+    /// If `self` is [`Int32`](Bson::Int32), return its value. Returns `default` otherwise.
+    pub fn unwrap_i32_or(&self, default: i32) -> i32 {
+        self.as_i32().unwrap_or(default)
+    }
+
+    /// If `self` is [`Int64`](Bson::Int64), return its value. Returns `default` otherwise.
+    pub fn unwrap_i64_or(&self, default: i64) -> i64 {
+        self.as_i64().unwrap_or(default)
+    }
+
+    /// If `self` is [`Boolean`](Bson::Boolean), return its value. Returns `default` otherwise.
+    pub fn unwrap_bool_or(&self, default: bool) -> bool {
+        self.as_bool().unwrap_or(default)
+    }
+
+    /// If `self` is [`Double`](Bson::Double), return its value. Returns `default` otherwise.
+    pub fn unwrap_f64_or(&self, default: f64) -> f64 {
+        self.as_f64().unwrap_or(default)
+    }
 }
 
 /// Represents a BSON timestamp value.
@@ -994,6 +1643,43 @@ impl Display for Timestamp {
 }
 
 impl Timestamp {
+    /// Adds `other` to this timestamp, returning `None` if the result would overflow.
+    ///
+    /// The timestamps are treated as 64-bit counters with `time` as the high 32 bits and
+    /// `increment` as the low 32 bits, so an overflowing `increment` carries into `time`.
+    ///
+    /// ```
+    /// # use bson::Timestamp;
+    /// let ts = Timestamp { time: 1, increment: u32::MAX };
+    /// let one = Timestamp { time: 0, increment: 1 };
+    /// assert_eq!(ts.checked_add(one), Some(Timestamp { time: 2, increment: 0 }));
+    /// ```
+    pub fn checked_add(self, other: Timestamp) -> Option<Timestamp> {
+        self.as_u64()
+            .checked_add(other.as_u64())
+            .map(Self::from_u64)
+    }
+
+    /// Subtracts `other` from this timestamp, returning `None` if the result would underflow.
+    ///
+    /// See [`Timestamp::checked_add`] for details on how the timestamps are combined.
+    pub fn checked_sub(self, other: Timestamp) -> Option<Timestamp> {
+        self.as_u64()
+            .checked_sub(other.as_u64())
+            .map(Self::from_u64)
+    }
+
+    fn as_u64(self) -> u64 {
+        ((self.time as u64) << 32) | self.increment as u64
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Self {
+            time: (value >> 32) as u32,
+            increment: value as u32,
+        }
+    }
+
     pub(crate) fn to_le_bytes(self) -> [u8; 8] {
         let mut out = [0; 8];
         out[0..4].copy_from_slice(&self.increment.to_le_bytes());
@@ -1030,7 +1716,36 @@ pub struct Regex {
 }
 
 impl Regex {
-    #[cfg(any(test, feature = "serde"))]
+    /// Creates a new [`Regex`], validating its parts.
+    ///
+    /// An error is returned if the pattern contains a null byte or if the options contain a
+    /// character other than those listed in the documentation for [`Regex::options`]. The options
+    /// are sorted into alphabetical order, and any duplicates are removed.
+    ///
+    /// ```
+    /// use bson::Regex;
+    ///
+    /// let regex = Regex::new("^abc", "mi")?;
+    /// assert_eq!(regex.options.as_str(), "im");
+    /// assert!(Regex::new("^abc", "q").is_err());
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn new(pattern: impl AsRef<str>, options: impl AsRef<str>) -> crate::error::Result<Self> {
+        let mut chars: Vec<_> = options.as_ref().chars().collect();
+        if let Some(invalid) = chars.iter().find(|c| !"ilmsux".contains(**c)) {
+            return Err(crate::error::Error::regex(format!(
+                "invalid regex option {:?}",
+                invalid
+            )));
+        }
+        chars.sort_unstable();
+        chars.dedup();
+        Ok(Self {
+            pattern: pattern.as_ref().try_into()?,
+            options: chars.into_iter().collect::<String>().try_into()?,
+        })
+    }
+
     pub(crate) fn from_strings(
         pattern: impl AsRef<str>,
         options: impl AsRef<str>,
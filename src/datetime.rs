@@ -1,6 +1,7 @@
 //! Module containing functionality related to BSON DateTimes.
 //! For more information, see the documentation for the [`DateTime`] type.
 pub(crate) mod builder;
+mod format;
 
 use std::{
     convert::TryInto,
@@ -15,7 +16,7 @@ use chrono::{LocalResult, TimeZone, Utc};
     any(feature = "chrono-0_4", feature = "time-0_3")
 ))]
 use serde::{Deserialize, Deserializer, Serialize};
-use time::format_description::well_known::Rfc3339;
+use time::format_description::well_known::{Iso8601, Rfc3339};
 
 pub use crate::datetime::builder::DateTimeBuilder;
 use crate::error::{Error, Result};
@@ -391,11 +392,76 @@ impl crate::DateTime {
         }
     }
 
+    /// Adds `duration` to the [`DateTime`], returning `None` if the result would be later than
+    /// [`DateTime::MAX`].
+    ///
+    /// As [`DateTime`] only have millisecond-precision this will only use the whole milliseconds
+    /// of `duration`.
+    pub const fn checked_add_std_duration(self, duration: Duration) -> Option<Self> {
+        let millis = duration.as_millis();
+        if millis > u64::MAX as u128 {
+            return None;
+        }
+        // computed in i128 so that durations longer than i64::MAX milliseconds can still be added
+        // to dates before the Unix epoch
+        let result = self.0 as i128 + millis as i128;
+        if result > i64::MAX as i128 {
+            None
+        } else {
+            Some(Self::from_millis(result as i64))
+        }
+    }
+
+    /// Subtracts `duration` from the [`DateTime`], returning `None` if the result would be earlier
+    /// than [`DateTime::MIN`].
+    ///
+    /// As [`DateTime`] only have millisecond-precision this will only use the whole milliseconds
+    /// of `duration`.
+    pub const fn checked_sub_std_duration(self, duration: Duration) -> Option<Self> {
+        let millis = duration.as_millis();
+        if millis > u64::MAX as u128 {
+            return None;
+        }
+        // computed in i128 so that durations longer than i64::MAX milliseconds can still be
+        // subtracted from dates after the Unix epoch
+        let result = self.0 as i128 - millis as i128;
+        if result < i64::MIN as i128 {
+            None
+        } else {
+            Some(Self::from_millis(result as i64))
+        }
+    }
+
     /// Convert this [`DateTime`] to an RFC 3339 formatted string.
     pub fn try_to_rfc3339_string(self) -> Result<String> {
         self.to_time_0_3().format(&Rfc3339).map_err(Error::datetime)
     }
 
+    /// Format this [`DateTime`] in UTC using a `strftime`-style `pattern`, without requiring the
+    /// `chrono-0_4` feature.
+    ///
+    /// The following specifiers are supported:
+    /// - `%Y`: the year, padded to at least four digits
+    /// - `%m`: the month, from `01` to `12`
+    /// - `%d`: the day of the month, from `01` to `31`
+    /// - `%H`: the hour, from `00` to `23`
+    /// - `%M`: the minute, from `00` to `59`
+    /// - `%S`: the second, from `00` to `59`
+    /// - `%3f`: the millisecond, from `000` to `999`
+    /// - `%%`: a literal `%`
+    ///
+    /// An error is returned if the pattern contains any other specifier.
+    ///
+    /// ```
+    /// # use bson::DateTime;
+    /// let dt = DateTime::parse_rfc3339_str("2020-06-09T10:58:07.095Z")?;
+    /// assert_eq!(dt.format_custom("%d/%m/%Y %H:%M:%S.%3f")?, "09/06/2020 10:58:07.095");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn format_custom(&self, pattern: &str) -> Result<String> {
+        format::format_custom(self, pattern)
+    }
+
     /// Convert the given RFC 3339 formatted string to a [`DateTime`], truncating it to millisecond
     /// precision.
     pub fn parse_rfc3339_str(s: impl AsRef<str>) -> Result<Self> {
@@ -403,13 +469,71 @@ impl crate::DateTime {
         Ok(Self::from_time_0_3(odt))
     }
 
+    /// Convert the given ISO 8601 formatted string to a [`DateTime`], truncating it to millisecond
+    /// precision.
+    ///
+    /// This accepts a broader set of inputs than [`DateTime::parse_rfc3339_str`], including
+    /// fractional seconds of any precision up to nanoseconds, offsets such as `+05:30` and `Z` as
+    /// shorthand for UTC.
+    ///
+    /// ```
+    /// # use bson::DateTime;
+    /// let dt = DateTime::parse_iso8601_str("2020-06-01T12:30:00.123456+05:30")?;
+    /// assert_eq!(dt, DateTime::parse_rfc3339_str("2020-06-01T07:00:00.123Z")?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_iso8601_str(s: impl AsRef<str>) -> Result<Self> {
+        let s = s.as_ref();
+        let odt = time::OffsetDateTime::parse(s, &Rfc3339)
+            .or_else(|_| time::OffsetDateTime::parse(s, &Iso8601::PARSING))
+            .map_err(Error::datetime)?;
+        Ok(Self::from_time_0_3(odt))
+    }
+
+    /// Convert this [`DateTime`] to a document in its canonical extended JSON form, i.e.
+    /// `{ "$date": { "$numberLong": "<millis>" } }`.
+    ///
+    /// ```
+    /// # use bson::{doc, DateTime};
+    /// let dt = DateTime::from_millis(1_591_700_287_095);
+    /// assert_eq!(
+    ///     dt.to_timestamp_document(),
+    ///     doc! { "$date": { "$numberLong": "1591700287095" } }
+    /// );
+    /// ```
+    pub fn to_timestamp_document(&self) -> crate::Document {
+        crate::doc! { "$date": { "$numberLong": self.0.to_string() } }
+    }
+
+    /// Convert a document of the form produced by [`DateTime::to_timestamp_document`] back to a
+    /// [`DateTime`]. An error is returned if the document has any other shape.
+    pub fn from_timestamp_document(doc: &crate::Document) -> Result<Self> {
+        let invalid = || {
+            Error::datetime(format!(
+                "expected a canonical extended JSON date document, got {}",
+                doc
+            ))
+        };
+        let date = match doc.get_document("$date") {
+            Ok(date) if doc.len() == 1 && date.len() == 1 => date,
+            _ => return Err(invalid()),
+        };
+        let millis = date
+            .get_str("$numberLong")
+            .ok()
+            .and_then(|millis| millis.parse().ok())
+            .ok_or_else(invalid)?;
+        Ok(Self::from_millis(millis))
+    }
+
     /// Returns the time elapsed since `earlier`, or `None` if the given `DateTime` is later than
     /// this one.
     pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
         if earlier.0 > self.0 {
             return None;
         }
-        Some(Duration::from_millis((self.0 - earlier.0) as u64))
+        // the difference between any two i64 values fits in a u64
+        Some(Duration::from_millis(self.0.wrapping_sub(earlier.0) as u64))
     }
 
     /// Returns the time elapsed since `earlier`, or a [`Duration`] of zero if the given `DateTime`
@@ -418,6 +542,105 @@ impl crate::DateTime {
         self.checked_duration_since(earlier)
             .unwrap_or(Duration::ZERO)
     }
+
+    /// Returns the `(year, month, day)` of this [`DateTime`] in UTC, with `month` and `day`
+    /// starting at 1.
+    ///
+    /// Unlike the conversions to [`time::OffsetDateTime`], this supports the entire range between
+    /// [`DateTime::MIN`] and [`DateTime::MAX`].
+    ///
+    /// ```
+    /// # use bson::DateTime;
+    /// let dt = DateTime::parse_rfc3339_str("2024-02-29T10:00:00Z")?;
+    /// assert_eq!(dt.date_parts(), (2024, 2, 29));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn date_parts(&self) -> (i32, u8, u8) {
+        let (year, month, day) = civil_from_days(self.days_since_epoch());
+        (year as i32, month, day)
+    }
+
+    /// Returns the ISO 8601 day of the week of this [`DateTime`] in UTC, from 1 for Monday to 7
+    /// for Sunday.
+    pub fn day_of_week_iso(&self) -> u8 {
+        // January 1, 1970 was a Thursday
+        ((self.days_since_epoch() + 3).rem_euclid(7) + 1) as u8
+    }
+
+    /// Returns the ISO 8601 week number of this [`DateTime`] in UTC, from 1 to 53.
+    ///
+    /// Per ISO 8601, the first week of a year is the one containing its first Thursday, so days
+    /// at the start of January may belong to the last week of the previous year and days at the
+    /// end of December may belong to the first week of the next one.
+    ///
+    /// ```
+    /// # use bson::DateTime;
+    /// let dt = DateTime::parse_rfc3339_str("2023-01-01T00:00:00Z")?;
+    /// assert_eq!(dt.week_number_iso(), 52);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn week_number_iso(&self) -> u8 {
+        let days = self.days_since_epoch();
+        let (year, ..) = civil_from_days(days);
+        let ordinal = days - days_from_civil(year, 1, 1) + 1;
+        let week = (ordinal - self.day_of_week_iso() as i64 + 10) / 7;
+        if week < 1 {
+            iso_weeks_in_year(year - 1)
+        } else if week > iso_weeks_in_year(year) as i64 {
+            1
+        } else {
+            week as u8
+        }
+    }
+
+    fn days_since_epoch(&self) -> i64 {
+        self.0.div_euclid(MILLIS_PER_DAY)
+    }
+}
+
+const MILLIS_PER_DAY: i64 = 24 * 60 * 60 * 1000;
+
+/// Converts a number of days since January 1, 1970 into a proleptic Gregorian `(year, month,
+/// day)`.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Converts a proleptic Gregorian date into the number of days since January 1, 1970.
+///
+/// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the number of ISO 8601 weeks in the given year, which is 53 if the year starts or ends
+/// on a Thursday and 52 otherwise.
+fn iso_weeks_in_year(year: i64) -> u8 {
+    let weekday = |days: i64| (days + 3).rem_euclid(7) + 1;
+    if weekday(days_from_civil(year, 1, 1)) == 4 || weekday(days_from_civil(year, 12, 31)) == 4 {
+        53
+    } else {
+        52
+    }
 }
 
 impl fmt::Debug for crate::DateTime {
@@ -0,0 +1,53 @@
+use std::fmt::Write;
+
+use crate::{
+    datetime::{DateTime, MILLIS_PER_DAY},
+    error::{Error, Result},
+};
+
+/// Formats `dt` in UTC according to a `strftime`-style `pattern`.
+///
+/// Supported specifiers are `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%3f` and `%%`; any other
+/// specifier results in an error.
+pub(crate) fn format_custom(dt: &DateTime, pattern: &str) -> Result<String> {
+    let (year, month, day) = dt.date_parts();
+    let millis_of_day = dt.0.rem_euclid(MILLIS_PER_DAY);
+    let hour = millis_of_day / (60 * 60 * 1000);
+    let minute = millis_of_day / (60 * 1000) % 60;
+    let second = millis_of_day / 1000 % 60;
+    let millis = millis_of_day % 1000;
+
+    let mut out = String::with_capacity(pattern.len() + 16);
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        // writing to a String cannot fail
+        let _ = match chars.next() {
+            Some('Y') if year < 0 => write!(out, "-{:04}", year.unsigned_abs()),
+            Some('Y') => write!(out, "{:04}", year),
+            Some('m') => write!(out, "{:02}", month),
+            Some('d') => write!(out, "{:02}", day),
+            Some('H') => write!(out, "{:02}", hour),
+            Some('M') => write!(out, "{:02}", minute),
+            Some('S') => write!(out, "{:02}", second),
+            Some('%') => write!(out, "%"),
+            Some('3') if chars.next() == Some('f') => write!(out, "{:03}", millis),
+            Some(other) => {
+                return Err(Error::datetime(format!(
+                    "unsupported format specifier %{} in pattern {:?}",
+                    other, pattern
+                )))
+            }
+            None => {
+                return Err(Error::datetime(format!(
+                    "incomplete format specifier at the end of pattern {:?}",
+                    pattern
+                )))
+            }
+        };
+    }
+    Ok(out)
+}
@@ -22,6 +22,7 @@
 //! Deserializer
 
 mod raw;
+pub mod seed;
 mod serde;
 
 pub use self::serde::Deserializer;
@@ -93,7 +94,58 @@ where
     deserialize_from_bson(Bson::Document(doc))
 }
 
+/// Returns a [`MapAccess`](::serde::de::MapAccess) over the fields of the provided [`Document`],
+/// for driving a [`Visitor`](::serde::de::Visitor) directly without a [`Deserializer`].
+///
+/// Fields are visited in order. Keys are passed to the seed as borrowed strings, and each value is
+/// deserialized from a clone of it only when requested.
+///
+/// ```
+/// use bson::{de::bson_as_map_access, doc};
+/// use serde::de::MapAccess;
+///
+/// let doc = doc! { "a": 1, "b": 2 };
+/// let mut access = bson_as_map_access(&doc);
+/// let mut sum = 0;
+/// while let Some((_key, value)) = access.next_entry::<&str, i32>()? {
+///     sum += value;
+/// }
+/// assert_eq!(sum, 3);
+/// # Ok::<(), bson::error::Error>(())
+/// ```
+pub fn bson_as_map_access(doc: &Document) -> impl ::serde::de::MapAccess<'_, Error = Error> {
+    self::serde::DocumentRefAccess::new(doc)
+}
+
+/// Returns a [`SeqAccess`](::serde::de::SeqAccess) over the elements of the provided array, for
+/// driving a [`Visitor`](::serde::de::Visitor) directly without a [`Deserializer`].
+///
+/// Elements are visited in order, and each is deserialized from a clone of it only when requested.
+pub fn bson_as_seq_access(array: &[Bson]) -> impl ::serde::de::SeqAccess<'_, Error = Error> {
+    self::serde::ArrayRefAccess::new(array)
+}
+
 /// Deserialize an instance of type `T` from an I/O stream of BSON.
+///
+/// Exactly one document is read from the stream, so this can be called repeatedly to read a
+/// sequence of documents.
+///
+/// ```
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct Cat {
+///     name: String,
+/// }
+///
+/// let mut bytes = bson::serialize_to_vec(&bson::doc! { "name": "Tom" })?;
+/// bytes.extend(bson::serialize_to_vec(&bson::doc! { "name": "Felix" })?);
+///
+/// let mut reader = std::io::Cursor::new(bytes);
+/// let first: Cat = bson::deserialize_from_reader(&mut reader)?;
+/// let second: Cat = bson::deserialize_from_reader(&mut reader)?;
+/// assert_eq!((first.name.as_str(), second.name.as_str()), ("Tom", "Felix"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub fn deserialize_from_reader<R, T>(reader: R) -> Result<T>
 where
     T: DeserializeOwned,
@@ -111,6 +163,108 @@ where
     deserialize_from_raw(raw::Deserializer::new(bytes)?)
 }
 
+/// Deserialize an instance of type `T` from a slice of BSON bytes, using the provided options to
+/// configure the deserializer.
+///
+/// ```
+/// # use serde::Deserialize;
+/// use bson::{de::DeserializerOptions, rawdoc};
+///
+/// #[derive(Deserialize)]
+/// struct Foo {
+///     name: String,
+/// }
+///
+/// let mut bytes = rawdoc! { "name": "a" }.into_bytes();
+/// // overwrite the "a" with an invalid UTF-8 byte
+/// bytes[14] = 0xFF;
+///
+/// let options = DeserializerOptions::builder().utf8_lossy(true).build();
+/// let foo: Foo = bson::de::deserialize_with_options(&bytes, options)?;
+/// assert_eq!(foo.name, "\u{FFFD}");
+/// # Ok::<(), bson::error::Error>(())
+/// ```
+pub fn deserialize_with_options<'de, T>(bytes: &'de [u8], options: DeserializerOptions) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    deserialize_from_raw(raw::Deserializer::new_with_options(bytes, &options)?)
+}
+
+/// Options used to configure the deserializer used by [`deserialize_with_options`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DeserializerOptions {
+    /// Whether invalid UTF-8 in strings should be replaced with the Unicode replacement character
+    /// rather than causing an error. This has the same effect as wrapping the target type in
+    /// [`Utf8LossyDeserialization`](crate::serde_helpers::Utf8LossyDeserialization).
+    ///
+    /// The default is false.
+    pub utf8_lossy: bool,
+
+    /// Whether documents may contain the same key more than once. When this is false, encountering
+    /// a duplicate key in any document is an error.
+    ///
+    /// The default is true.
+    pub allow_duplicate_keys: bool,
+
+    /// The maximum nesting depth of documents and arrays, where the top-level document has a
+    /// depth of 1. Exceeding this depth is an error.
+    ///
+    /// The default is `None`, which places no limit on the depth.
+    pub max_depth: Option<u32>,
+}
+
+impl Default for DeserializerOptions {
+    fn default() -> Self {
+        Self {
+            utf8_lossy: false,
+            allow_duplicate_keys: true,
+            max_depth: None,
+        }
+    }
+}
+
+impl DeserializerOptions {
+    /// Create a builder struct used to construct a [`DeserializerOptions`].
+    pub fn builder() -> DeserializerOptionsBuilder {
+        DeserializerOptionsBuilder {
+            options: Default::default(),
+        }
+    }
+}
+
+/// A builder used to construct a [`DeserializerOptions`].
+#[derive(Debug, Clone)]
+pub struct DeserializerOptionsBuilder {
+    options: DeserializerOptions,
+}
+
+impl DeserializerOptionsBuilder {
+    /// Set the value for [`DeserializerOptions::utf8_lossy`].
+    pub fn utf8_lossy(mut self, val: impl Into<bool>) -> Self {
+        self.options.utf8_lossy = val.into();
+        self
+    }
+
+    /// Set the value for [`DeserializerOptions::allow_duplicate_keys`].
+    pub fn allow_duplicate_keys(mut self, val: impl Into<bool>) -> Self {
+        self.options.allow_duplicate_keys = val.into();
+        self
+    }
+
+    /// Set the value for [`DeserializerOptions::max_depth`].
+    pub fn max_depth(mut self, val: impl Into<Option<u32>>) -> Self {
+        self.options.max_depth = val.into();
+        self
+    }
+
+    /// Consume this builder and produce a [`DeserializerOptions`].
+    pub fn build(self) -> DeserializerOptions {
+        self.options
+    }
+}
+
 pub(crate) fn deserialize_from_raw<'de, T: Deserialize<'de>>(
     deserializer: raw::Deserializer<'de>,
 ) -> Result<T> {
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashSet};
 
 use serde::{
     de::{value::BorrowedStrDeserializer, Error as SerdeError, IntoDeserializer, MapAccess},
@@ -47,15 +47,29 @@ pub(crate) struct Deserializer<'de> {
 struct DeserializerOptions {
     utf8_lossy: bool,
     human_readable: bool,
+    allow_duplicate_keys: bool,
+    max_depth: Option<u32>,
+    /// The number of documents and arrays enclosing the current element.
+    depth: u32,
 }
 
 impl<'de> Deserializer<'de> {
     pub(crate) fn new(buf: &'de [u8]) -> Result<Self> {
+        Self::new_with_options(buf, &Default::default())
+    }
+
+    pub(crate) fn new_with_options(
+        buf: &'de [u8],
+        options: &crate::de::DeserializerOptions,
+    ) -> Result<Self> {
         Ok(Self {
             element: RawElement::toplevel(buf)?,
             options: DeserializerOptions {
-                utf8_lossy: false,
+                utf8_lossy: options.utf8_lossy,
                 human_readable: false,
+                allow_duplicate_keys: options.allow_duplicate_keys,
+                max_depth: options.max_depth,
+                depth: 0,
             },
         })
     }
@@ -243,7 +257,10 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
                 };
                 visitor.visit_enum(DocumentAccess::new(doc, self.options.clone())?)
             }
-            t => Err(Error::custom(format!("expected enum, instead got {:?}", t))),
+            t => Err(Error::custom(format!(
+                "expected enum, instead got {}",
+                t.type_name()
+            ))),
         }
     }
 
@@ -274,8 +291,8 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
             RAW_DOCUMENT_NEWTYPE => {
                 if self.element.element_type() != ElementType::EmbeddedDocument {
                     return Err(serde::de::Error::custom(format!(
-                        "expected raw document, instead got {:?}",
-                        self.element.element_type()
+                        "expected raw document, instead got {}",
+                        self.element.element_type().type_name()
                     )));
                 }
 
@@ -284,8 +301,8 @@ impl<'de> serde::de::Deserializer<'de> for Deserializer<'de> {
             RAW_ARRAY_NEWTYPE => {
                 if self.element.element_type() != ElementType::Array {
                     return Err(serde::de::Error::custom(format!(
-                        "expected raw array, instead got {:?}",
-                        self.element.element_type()
+                        "expected raw array, instead got {}",
+                        self.element.element_type().type_name()
                     )));
                 }
 
@@ -320,14 +337,31 @@ struct DocumentAccess<'de> {
     iter: RawIter<'de>,
     elem: Option<RawElement<'de>>,
     options: DeserializerOptions,
+    /// The keys visited so far, tracked only when duplicate keys are disallowed.
+    seen_keys: Option<HashSet<&'de str>>,
 }
 
 impl<'de> DocumentAccess<'de> {
-    fn new(doc: &'de RawDocument, options: DeserializerOptions) -> Result<Self> {
+    fn new(doc: &'de RawDocument, mut options: DeserializerOptions) -> Result<Self> {
+        options.depth += 1;
+        if let Some(max_depth) = options.max_depth {
+            if options.depth > max_depth {
+                return Err(Error::deserialization(format!(
+                    "document exceeds the maximum nesting depth of {}",
+                    max_depth
+                )));
+            }
+        }
+        let seen_keys = if options.allow_duplicate_keys {
+            None
+        } else {
+            Some(HashSet::new())
+        };
         Ok(Self {
             iter: doc.iter_elements(),
             elem: None,
             options,
+            seen_keys,
         })
     }
 
@@ -362,9 +396,16 @@ impl<'de> serde::de::MapAccess<'de> for DocumentAccess<'de> {
         self.advance()?;
         match &self.elem {
             None => Ok(None),
-            Some(elem) => seed
-                .deserialize(BorrowedStrDeserializer::new(elem.key().as_str()))
-                .map(Some),
+            Some(elem) => {
+                let key = elem.key().as_str();
+                if let Some(ref mut seen_keys) = self.seen_keys {
+                    if !seen_keys.insert(key) {
+                        return Err(Error::deserialization(format!("duplicate key \"{}\"", key)));
+                    }
+                }
+                seed.deserialize(BorrowedStrDeserializer::new(key))
+                    .map(Some)
+            }
         }
     }
 
@@ -0,0 +1,101 @@
+//! Support for passing context into the deserialization of a [`Document`] via
+//! [`DeserializeSeed`].
+
+use std::fmt;
+
+use serde::de::{DeserializeSeed, Deserializer, Error as _, MapAccess, Visitor};
+
+use crate::{Bson, Document};
+
+/// Context that is given each top-level field of a document as it is deserialized by a
+/// [`DocumentSeed`].
+pub trait DocumentContext {
+    /// Called with each top-level key and value, in order, after the value has been deserialized.
+    /// Returning an error aborts deserialization with that message.
+    fn visit_field(&mut self, key: &str, value: &Bson) -> Result<(), String>;
+}
+
+impl<C: DocumentContext + ?Sized> DocumentContext for &mut C {
+    fn visit_field(&mut self, key: &str, value: &Bson) -> Result<(), String> {
+        (**self).visit_field(key, value)
+    }
+}
+
+/// A [`DeserializeSeed`] that deserializes a [`Document`] while passing each of its top-level
+/// fields to a [`DocumentContext`].
+///
+/// Pass a mutable reference as the context to inspect it once deserialization is complete:
+///
+/// ```
+/// use bson::{
+///     de::seed::{DocumentContext, DocumentSeed},
+///     doc,
+///     Bson,
+///     Deserializer,
+/// };
+/// use serde::de::DeserializeSeed;
+///
+/// #[derive(Default)]
+/// struct FieldCounter {
+///     fields: usize,
+/// }
+///
+/// impl DocumentContext for FieldCounter {
+///     fn visit_field(&mut self, _key: &str, _value: &Bson) -> Result<(), String> {
+///         self.fields += 1;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut counter = FieldCounter::default();
+/// let seed = DocumentSeed { context: &mut counter };
+/// let doc = seed.deserialize(Deserializer::new(Bson::Document(doc! { "a": 1, "b": 2 })))?;
+/// assert_eq!(doc, doc! { "a": 1, "b": 2 });
+/// assert_eq!(counter.fields, 2);
+/// # Ok::<(), bson::error::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DocumentSeed<C> {
+    /// The context given each field.
+    pub context: C,
+}
+
+impl<'de, C: DocumentContext> DeserializeSeed<'de> for DocumentSeed<C> {
+    type Value = Document;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Document, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DocumentSeedVisitor {
+            context: self.context,
+        })
+    }
+}
+
+struct DocumentSeedVisitor<C> {
+    context: C,
+}
+
+impl<'de, C: DocumentContext> Visitor<'de> for DocumentSeedVisitor<C> {
+    type Value = Document;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a document")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Document, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut doc = Document::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let value: Bson = map.next_value()?;
+            self.context
+                .visit_field(&key, &value)
+                .map_err(A::Error::custom)?;
+            doc.insert(key, value);
+        }
+        Ok(doc)
+    }
+}
@@ -81,7 +81,7 @@ impl<'de> Visitor<'de> for ObjectIdVisitor {
             bson => {
                 let err = format!(
                     "expected map containing extended-JSON formatted ObjectId, instead found {}",
-                    bson
+                    bson.type_name()
                 );
                 Err(serde::de::Error::custom(err))
             }
@@ -112,7 +112,10 @@ impl<'de> Deserialize<'de> for Document {
             if let Bson::Document(doc) = bson {
                 Ok(doc)
             } else {
-                let err = format!("expected document, found extended JSON data type: {}", bson);
+                let err = format!(
+                    "expected document, found extended JSON data type: {}",
+                    bson.type_name()
+                );
                 Err(serde::de::Error::invalid_type(Unexpected::Map, &&err[..]))
             }
         })
@@ -617,6 +620,34 @@ impl Deserializer {
         }
     }
 
+    /// Returns a reference to the [`Bson`] value that has not yet been deserialized, if any.
+    ///
+    /// When deserializing through a `&mut Deserializer`, the value is only consumed if
+    /// deserialization succeeds, so after a failure this returns the original input.
+    ///
+    /// ```
+    /// use bson::{Bson, Deserializer};
+    /// use serde::Deserialize;
+    ///
+    /// let mut deserializer = Deserializer::new(Bson::String("not a number".to_string()));
+    /// assert!(i32::deserialize(&mut deserializer).is_err());
+    /// assert_eq!(deserializer.remaining(), Some(&Bson::String("not a number".to_string())));
+    ///
+    /// let mut deserializer = Deserializer::new(Bson::Int32(1));
+    /// assert_eq!(i32::deserialize(&mut deserializer)?, 1);
+    /// assert_eq!(deserializer.remaining(), None);
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn remaining(&self) -> Option<&Bson> {
+        self.value.as_ref()
+    }
+
+    /// Consumes this [`Deserializer`], returning the [`Bson`] value that has not yet been
+    /// deserialized, if any. See [`Deserializer::remaining`] for more details.
+    pub fn into_inner(self) -> Option<Bson> {
+        self.value
+    }
+
     fn deserialize_next<'de, V>(mut self, visitor: V, hint: DeserializerHint) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
@@ -814,21 +845,29 @@ impl<'de> de::Deserializer<'de> for Deserializer {
             ),
             RAW_BSON_NEWTYPE => self.deserialize_next(visitor, DeserializerHint::RawBson),
             RAW_DOCUMENT_NEWTYPE => {
-                if !matches!(self.value, Some(Bson::Document(_))) {
-                    return Err(serde::de::Error::custom(format!(
-                        "expected raw document, instead got {:?}",
-                        self.value
-                    )));
+                match self.value {
+                    Some(Bson::Document(_)) => {}
+                    Some(ref other) => {
+                        return Err(serde::de::Error::custom(format!(
+                            "expected raw document, instead got {}",
+                            other.type_name()
+                        )))
+                    }
+                    None => return Err(Error::end_of_stream()),
                 }
 
                 self.deserialize_next(visitor, DeserializerHint::RawBson)
             }
             RAW_ARRAY_NEWTYPE => {
-                if !matches!(self.value, Some(Bson::Array(_))) {
-                    return Err(serde::de::Error::custom(format!(
-                        "expected raw array, instead got {:?}",
-                        self.value
-                    )));
+                match self.value {
+                    Some(Bson::Array(_)) => {}
+                    Some(ref other) => {
+                        return Err(serde::de::Error::custom(format!(
+                            "expected raw array, instead got {}",
+                            other.type_name()
+                        )))
+                    }
+                    None => return Err(Error::end_of_stream()),
                 }
 
                 self.deserialize_next(visitor, DeserializerHint::RawBson)
@@ -870,6 +909,78 @@ impl<'de> de::Deserializer<'de> for Deserializer {
     }
 }
 
+macro_rules! forward_to_owned {
+    ($(
+        $name:ident ( $( $arg:ident : $ty:ty ),* );
+    )*) => {
+        $(
+            fn $name<V>(self, $( $arg: $ty, )* visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>,
+            {
+                self.deserialize_owned(|de| de.$name($( $arg, )* visitor))
+            }
+        )*
+    };
+}
+
+impl Deserializer {
+    /// Deserializes a clone of the remaining value with an owned [`Deserializer`], only consuming
+    /// the value if that succeeds so that it can be recovered after a failure.
+    fn deserialize_owned<T>(&mut self, f: impl FnOnce(Deserializer) -> Result<T>) -> Result<T> {
+        let value = self.value.clone().ok_or_else(Error::end_of_stream)?;
+        let result = f(Deserializer::new_with_options(value, self.options.clone()));
+        if result.is_ok() {
+            self.value = None;
+        }
+        result
+    }
+}
+
+/// Deserializing through a mutable reference leaves the value in place if deserialization fails,
+/// where it can be inspected with [`Deserializer::remaining`] or recovered with
+/// [`Deserializer::into_inner`]. This requires cloning the value, so deserializing an owned
+/// [`Deserializer`] should be preferred when recovery is not needed.
+impl<'de> de::Deserializer<'de> for &mut Deserializer {
+    type Error = Error;
+
+    fn is_human_readable(&self) -> bool {
+        de::Deserializer::is_human_readable(&**self)
+    }
+
+    forward_to_owned! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_newtype_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+}
+
 struct EnumDeserializer {
     val: Bson,
     deserializer: VariantDeserializer,
@@ -1091,6 +1202,85 @@ impl<'de> MapAccess<'de> for MapDeserializer {
     }
 }
 
+/// A [`MapAccess`] over the fields of a borrowed [`Document`]. Keys are given to the seed as
+/// borrowed strings, and each value is cloned only once the seed asks for it.
+pub(crate) struct DocumentRefAccess<'a> {
+    iter: crate::document::Iter<'a>,
+    value: Option<&'a Bson>,
+    len: usize,
+}
+
+impl<'a> DocumentRefAccess<'a> {
+    pub(crate) fn new(doc: &'a Document) -> Self {
+        Self {
+            iter: doc.iter(),
+            value: None,
+            len: doc.len(),
+        }
+    }
+}
+
+impl<'a> MapAccess<'a> for DocumentRefAccess<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+    where
+        K: DeserializeSeed<'a>,
+    {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.len -= 1;
+                self.value = Some(value);
+                seed.deserialize(de::value::BorrowedStrDeserializer::new(key))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value>
+    where
+        V: DeserializeSeed<'a>,
+    {
+        let value = self.value.take().ok_or_else(Error::end_of_stream)?;
+        seed.deserialize(Deserializer::new(value.clone()))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+/// A [`SeqAccess`] over the elements of a borrowed array. Each element is cloned only once the
+/// seed asks for it.
+pub(crate) struct ArrayRefAccess<'a> {
+    iter: std::slice::Iter<'a, Bson>,
+}
+
+impl<'a> ArrayRefAccess<'a> {
+    pub(crate) fn new(array: &'a [Bson]) -> Self {
+        Self { iter: array.iter() }
+    }
+}
+
+impl<'a> SeqAccess<'a> for ArrayRefAccess<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'a>,
+    {
+        self.iter
+            .next()
+            .map(|value| seed.deserialize(Deserializer::new(value.clone())))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
 impl<'de> de::Deserializer<'de> for MapDeserializer {
     type Error = Error;
 
@@ -1141,7 +1331,10 @@ impl<'de> Deserialize<'de> for Timestamp {
     {
         match Bson::deserialize(deserializer)? {
             Bson::Timestamp(timestamp) => Ok(timestamp),
-            _ => Err(serde::de::Error::custom("expecting Timestamp")),
+            other => Err(serde::de::Error::custom(format!(
+                "expecting Timestamp, got {}",
+                other.type_name()
+            ))),
         }
     }
 }
@@ -1153,7 +1346,10 @@ impl<'de> Deserialize<'de> for Regex {
     {
         match Bson::deserialize(deserializer)? {
             Bson::RegularExpression(regex) => Ok(regex),
-            _ => Err(serde::de::Error::custom("expecting Regex")),
+            other => Err(serde::de::Error::custom(format!(
+                "expecting RegularExpression, got {}",
+                other.type_name()
+            ))),
         }
     }
 }
@@ -1165,9 +1361,10 @@ impl<'de> Deserialize<'de> for JavaScriptCodeWithScope {
     {
         match Bson::deserialize(deserializer)? {
             Bson::JavaScriptCodeWithScope(code_with_scope) => Ok(code_with_scope),
-            _ => Err(serde::de::Error::custom(
-                "expecting JavaScriptCodeWithScope",
-            )),
+            other => Err(serde::de::Error::custom(format!(
+                "expecting JavaScriptCodeWithScope, got {}",
+                other.type_name()
+            ))),
         }
     }
 }
@@ -1179,9 +1376,9 @@ impl<'de> Deserialize<'de> for Binary {
     {
         match Bson::deserialize(deserializer)? {
             Bson::Binary(binary) => Ok(binary),
-            d => Err(serde::de::Error::custom(format!(
-                "expecting Binary but got {:?} instead",
-                d
+            other => Err(serde::de::Error::custom(format!(
+                "expecting Binary, got {}",
+                other.type_name()
             ))),
         }
     }
@@ -1194,9 +1391,9 @@ impl<'de> Deserialize<'de> for Decimal128 {
     {
         match Bson::deserialize(deserializer)? {
             Bson::Decimal128(d128) => Ok(d128),
-            o => Err(serde::de::Error::custom(format!(
-                "expecting Decimal128, got {:?}",
-                o
+            other => Err(serde::de::Error::custom(format!(
+                "expecting Decimal128, got {}",
+                other.type_name()
             ))),
         }
     }
@@ -1209,7 +1406,10 @@ impl<'de> Deserialize<'de> for DateTime {
     {
         match Bson::deserialize(deserializer)? {
             Bson::DateTime(dt) => Ok(dt),
-            _ => Err(serde::de::Error::custom("expecting DateTime")),
+            other => Err(serde::de::Error::custom(format!(
+                "expecting DateTime, got {}",
+                other.type_name()
+            ))),
         }
     }
 }
@@ -1221,7 +1421,10 @@ impl<'de> Deserialize<'de> for DbPointer {
     {
         match Bson::deserialize(deserializer)? {
             Bson::DbPointer(db_pointer) => Ok(db_pointer),
-            _ => Err(serde::de::Error::custom("expecting DbPointer")),
+            other => Err(serde::de::Error::custom(format!(
+                "expecting DbPointer, got {}",
+                other.type_name()
+            ))),
         }
     }
 }
@@ -21,6 +21,9 @@ use crate::{
 };
 
 /// A BSON document represented as an associative HashMap with insertion ordering.
+///
+/// Equality ignores the order of keys, including in nested documents, but not the order of array
+/// elements.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Document {
     inner: IndexMap<String, Bson, RandomState>,
@@ -132,6 +135,11 @@ pub struct Values<'a> {
     inner: indexmap::map::Values<'a, String, Bson>,
 }
 
+/// An iterator over mutable references to a [`Document`]'s values.
+pub struct ValuesMut<'a> {
+    inner: indexmap::map::ValuesMut<'a, String, Bson>,
+}
+
 /// An iterator over a [`Document`]'s keys and mutable values.
 pub struct IterMut<'a> {
     inner: indexmap::map::IterMut<'a, String, Bson>,
@@ -153,6 +161,14 @@ impl<'a> Iterator for Values<'a> {
     }
 }
 
+impl<'a> Iterator for ValuesMut<'a> {
+    type Item = &'a mut Bson;
+
+    fn next(&mut self) -> Option<&'a mut Bson> {
+        self.inner.next()
+    }
+}
+
 impl IntoIterator for Document {
     type Item = (String, Bson);
     type IntoIter = IntoIter;
@@ -185,6 +201,16 @@ impl FromIterator<(String, Bson)> for Document {
     }
 }
 
+impl<'a> FromIterator<(&'a str, Bson)> for Document {
+    fn from_iter<T: IntoIterator<Item = (&'a str, Bson)>>(iter: T) -> Self {
+        let mut doc = Document::new();
+        for (k, v) in iter {
+            doc.insert(k, v);
+        }
+        doc
+    }
+}
+
 impl Iterator for IntoIter {
     type Item = (String, Bson);
 
@@ -217,6 +243,13 @@ impl Document {
         }
     }
 
+    /// Returns a new empty document with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Document {
+        Document {
+            inner: IndexMap::with_capacity_and_hasher(capacity, RandomState::default()),
+        }
+    }
+
     /// Returns an iterator over the contents of the document.
     pub fn iter(&self) -> Iter {
         self.into_iter()
@@ -229,6 +262,234 @@ impl Document {
         }
     }
 
+    /// Returns an iterator over the fields of the document whose values are
+    /// [`Document`](Bson::Document)s, skipping all other fields.
+    ///
+    /// ```
+    /// # use bson::doc;
+    /// let doc = doc! { "a": { "x": 1 }, "b": 2, "c": { "y": 3 } };
+    /// let keys: Vec<_> = doc.iter_documents().map(|(key, _)| key).collect();
+    /// assert_eq!(keys, ["a", "c"]);
+    /// ```
+    pub fn iter_documents(&self) -> impl Iterator<Item = (&str, &Document)> {
+        self.iter()
+            .filter_map(|(k, v)| v.as_document().map(|v| (k.as_str(), v)))
+    }
+
+    /// Returns an iterator over the fields of the document whose values are
+    /// [`Array`](Bson::Array)s, skipping all other fields.
+    pub fn iter_arrays(&self) -> impl Iterator<Item = (&str, &Array)> {
+        self.iter()
+            .filter_map(|(k, v)| v.as_array().map(|v| (k.as_str(), v)))
+    }
+
+    /// Returns an iterator over the fields of the document whose values are
+    /// [`String`](Bson::String)s, skipping all other fields.
+    pub fn iter_strings(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.iter()
+            .filter_map(|(k, v)| v.as_str().map(|v| (k.as_str(), v)))
+    }
+
+    /// Returns an iterator over the fields of the document whose values are
+    /// [`Int32`](Bson::Int32)s, skipping all other fields.
+    pub fn iter_i32(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.iter()
+            .filter_map(|(k, v)| v.as_i32().map(|v| (k.as_str(), v)))
+    }
+
+    /// Returns an iterator over the fields of the document whose values are
+    /// [`Int64`](Bson::Int64)s, skipping all other fields.
+    pub fn iter_i64(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.iter()
+            .filter_map(|(k, v)| v.as_i64().map(|v| (k.as_str(), v)))
+    }
+
+    /// Returns the length in bytes of the longest top-level key in the document, or 0 if the
+    /// document is empty.
+    pub fn longest_key_length(&self) -> usize {
+        self.keys().map(String::len).max().unwrap_or(0)
+    }
+
+    /// Returns the sum of the lengths in bytes of the top-level keys in the document, not
+    /// including their null terminators.
+    pub fn total_keys_size(&self) -> usize {
+        self.keys().map(String::len).sum()
+    }
+
+    /// Returns the size in bytes of the smallest top-level value in the document when encoded as
+    /// BSON (see [`Bson::size_bytes`]), or 0 if the document is empty.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "id": 1, "name": "abc" };
+    /// assert_eq!(doc.longest_key_length(), 4);
+    /// assert_eq!(doc.total_keys_size(), 6);
+    /// assert_eq!(doc.shortest_value_size(), 4);
+    /// ```
+    pub fn shortest_value_size(&self) -> usize {
+        self.values().map(Bson::size_bytes).min().unwrap_or(0)
+    }
+
+    /// Returns the number of top-level values in the document with the given [`ElementType`].
+    ///
+    /// ```
+    /// use bson::{doc, spec::ElementType};
+    ///
+    /// let doc = doc! { "a": "x", "b": 1, "c": "y" };
+    /// assert_eq!(doc.count_type(ElementType::String), 2);
+    /// assert_eq!(doc.count_type(ElementType::Double), 0);
+    /// ```
+    pub fn count_type(&self, element_type: ElementType) -> usize {
+        self.values()
+            .filter(|v| v.element_type() == element_type)
+            .count()
+    }
+
+    /// Asserts that the document has exactly the given top-level keys, with values of the given
+    /// [`ElementType`]s. The order of the keys is not checked.
+    ///
+    /// This is intended for tests, where it gives more targeted diagnostics than comparing whole
+    /// documents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the document does not match, with a message listing every missing key, every key
+    /// whose value has the wrong type, and every unexpected key.
+    ///
+    /// ```
+    /// use bson::{doc, spec::ElementType};
+    ///
+    /// let doc = doc! { "name": "Garfield", "age": 45 };
+    /// doc.assert_shape(&[("name", ElementType::String), ("age", ElementType::Int32)]);
+    /// ```
+    #[track_caller]
+    pub fn assert_shape(&self, expected_keys: &[(&str, ElementType)]) {
+        let mut mismatches = Vec::new();
+        for (key, expected_type) in expected_keys {
+            match self.get(key) {
+                None => mismatches.push(format!(
+                    "  {:?}: missing, expected {:?}",
+                    key, expected_type
+                )),
+                Some(value) if value.element_type() != *expected_type => mismatches.push(format!(
+                    "  {:?}: expected {:?}, got {:?}",
+                    key,
+                    expected_type,
+                    value.element_type()
+                )),
+                Some(_) => {}
+            }
+        }
+        for (key, value) in self {
+            if !expected_keys.iter().any(|(expected, _)| expected == key) {
+                mismatches.push(format!(
+                    "  {:?}: unexpected {:?}",
+                    key,
+                    value.element_type()
+                ));
+            }
+        }
+        if !mismatches.is_empty() {
+            panic!(
+                "document does not have the expected shape:\n{}\ndocument: {}",
+                mismatches.join("\n"),
+                self
+            );
+        }
+    }
+
+    /// Returns the first top-level key-value pair for which `predicate` returns true, if any.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let doc = doc! { "a": "x", "b": 1, "c": 2 };
+    /// let found = doc.find(|_, value| matches!(value, Bson::Int32(_)));
+    /// assert_eq!(found, Some(("b", &Bson::Int32(1))));
+    /// ```
+    pub fn find<F>(&self, mut predicate: F) -> Option<(&str, &Bson)>
+    where
+        F: FnMut(&str, &Bson) -> bool,
+    {
+        self.iter()
+            .map(|(k, v)| (k.as_str(), v))
+            .find(|(k, v)| predicate(k, v))
+    }
+
+    /// Returns every top-level key-value pair for which `predicate` returns true, in order.
+    pub fn find_all<F>(&self, mut predicate: F) -> Vec<(&str, &Bson)>
+    where
+        F: FnMut(&str, &Bson) -> bool,
+    {
+        self.iter()
+            .map(|(k, v)| (k.as_str(), v))
+            .filter(|(k, v)| predicate(k, v))
+            .collect()
+    }
+
+    /// Consumes the document, returning a new document with the same keys in the same order, where
+    /// each value is the result of calling `f` with the key and the original value.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let doc = doc! { "a": 1, "b": "two" };
+    /// let doc = doc.map_values(|_, value| match value {
+    ///     Bson::Int32(i) => Bson::Int64(i.into()),
+    ///     other => other,
+    /// });
+    /// assert_eq!(doc, doc! { "a": 1_i64, "b": "two" });
+    /// ```
+    pub fn map_values<F: FnMut(&str, Bson) -> Bson>(self, mut f: F) -> Document {
+        self.into_iter()
+            .map(|(k, v)| {
+                let v = f(&k, v);
+                (k, v)
+            })
+            .collect()
+    }
+
+    /// Calls `f` with each key and a mutable reference to its value, in order.
+    pub fn map_values_in_place<F: FnMut(&str, &mut Bson)>(&mut self, mut f: F) {
+        for (k, v) in self.iter_mut() {
+            f(k, v);
+        }
+    }
+
+    /// Replaces the value for the given key with the result of calling `f` on it. Returns
+    /// `Ok(true)` if the key was present and `f` succeeded, and `Ok(false)` without calling `f` if
+    /// the key was absent. The field keeps its position in the document.
+    ///
+    /// If `f` returns an error, the error is returned and the field keeps its original value. This
+    /// requires keeping a copy of the value while `f` runs, so the value is cloned once per call.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let mut doc = doc! { "a": 1, "b": 2 };
+    /// let applied = doc.transform_field("a", |value| match value {
+    ///     Bson::Int32(i) => Ok(Bson::String(i.to_string())),
+    ///     other => Ok(other),
+    /// })?;
+    /// assert!(applied);
+    /// assert_eq!(doc, doc! { "a": "1", "b": 2 });
+    /// assert!(!doc.transform_field("x", Ok)?);
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn transform_field<F: FnOnce(Bson) -> Result<Bson>>(
+        &mut self,
+        key: &str,
+        f: F,
+    ) -> Result<bool> {
+        let Some(value) = self.inner.get_mut(key) else {
+            return Ok(false);
+        };
+        // `f` takes ownership of the value, so a copy is kept in place to restore on failure
+        *value = f(value.clone())?;
+        Ok(true)
+    }
+
     /// Removes all values from the document.
     pub fn clear(&mut self) {
         self.inner.clear();
@@ -245,6 +506,236 @@ impl Document {
         self.inner.get_mut(key.as_ref())
     }
 
+    /// Returns a reference to the [`Bson`] value found by following the given path of keys
+    /// through nested documents, if present. Returns `None` if any key along the path is absent
+    /// or if any value before the last key is not a document.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let doc = doc! { "a": { "b": { "c": 1 } } };
+    /// assert_eq!(doc.get_nested(&["a", "b", "c"]), Some(&Bson::Int32(1)));
+    /// assert_eq!(doc.get_nested(&["a", "x", "c"]), None);
+    /// ```
+    pub fn get_nested(&self, keys: &[&str]) -> Option<&Bson> {
+        let (last, path) = keys.split_last()?;
+        let mut doc = self;
+        for key in path {
+            match doc.get(key)? {
+                Bson::Document(d) => doc = d,
+                _ => return None,
+            }
+        }
+        doc.get(last)
+    }
+
+    /// Returns a mutable reference to the [`Bson`] value found by following the given path of
+    /// keys through nested documents, if present. See [`Document::get_nested`] for more details.
+    pub fn get_nested_mut(&mut self, keys: &[&str]) -> Option<&mut Bson> {
+        let (last, path) = keys.split_last()?;
+        let mut doc = self;
+        for key in path {
+            match doc.get_mut(key)? {
+                Bson::Document(d) => doc = d,
+                _ => return None,
+            }
+        }
+        doc.get_mut(last)
+    }
+
+    /// Returns a mutable reference to the value at the given dot-notation path such as `"a.b.c"`,
+    /// inserting the result of `f` as the value if it is absent. Any missing documents along the
+    /// path are created empty, and `f` is only called if the final value is missing.
+    ///
+    /// An error is returned if a value before the last key exists but is not a document. The
+    /// document is left unchanged in that case.
+    ///
+    /// ```
+    /// use bson::{doc, Bson};
+    ///
+    /// let mut doc = doc! { "a": { "x": 1 } };
+    /// *doc.get_path_or_insert_with("a.b.c", || Bson::Int32(0))? = Bson::Int32(5);
+    /// assert_eq!(doc, doc! { "a": { "x": 1, "b": { "c": 5 } } });
+    ///
+    /// assert_eq!(doc.get_path_or_insert_with("a.x", || Bson::Null)?, &Bson::Int32(1));
+    /// assert!(doc.get_path_or_insert_with("a.x.y", || Bson::Null).is_err());
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn get_path_or_insert_with<F: FnOnce() -> Bson>(
+        &mut self,
+        path: &str,
+        f: F,
+    ) -> Result<&mut Bson> {
+        let (parents, last) = match path.rsplit_once('.') {
+            Some((parents, last)) => (Some(parents), last),
+            None => (None, path),
+        };
+
+        let mut doc = self;
+        let mut end = 0;
+        for key in parents.into_iter().flat_map(|parents| parents.split('.')) {
+            end += key.len();
+            let value = doc
+                .entry(key)
+                .or_insert_with(|| Bson::Document(Document::new()));
+            doc = match value {
+                Bson::Document(d) => d,
+                other => {
+                    return Err(Error::value_access_unexpected_type(
+                        other.element_type(),
+                        ElementType::EmbeddedDocument,
+                    )
+                    .with_key(&path[..end]));
+                }
+            };
+            end += 1;
+        }
+        Ok(doc.entry(last).or_insert_with(f))
+    }
+
+    /// Returns a copy of this document containing a subset of its fields, similar to a MongoDB
+    /// projection. If `include` is true, only the given fields are kept; otherwise, all fields
+    /// except the given ones are kept. Fields in nested documents can be selected using
+    /// dot-notation paths such as `"a.b"`. The order of the remaining fields is preserved.
+    ///
+    /// Unlike a projection performed by the server, `_id` receives no special treatment and paths
+    /// are not followed into arrays.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "a": 1, "b": { "c": 2, "d": 3 } };
+    /// assert_eq!(doc.apply_projection(&["b.c"], true), doc! { "b": { "c": 2 } });
+    /// assert_eq!(doc.apply_projection(&["b.c"], false), doc! { "a": 1, "b": { "d": 3 } });
+    /// ```
+    pub fn apply_projection(&self, fields: &[&str], include: bool) -> Document {
+        let mut out = Document::new();
+        for (key, value) in self {
+            let mut whole = false;
+            let mut subpaths = Vec::new();
+            for field in fields {
+                match field.split_once('.') {
+                    Some((head, rest)) if head == key => subpaths.push(rest),
+                    None if field == key => whole = true,
+                    _ => {}
+                }
+            }
+
+            let projected = match value {
+                _ if whole => include.then(|| value.clone()),
+                Bson::Document(doc) if !subpaths.is_empty() => {
+                    Some(Bson::Document(doc.apply_projection(&subpaths, include)))
+                }
+                _ => (!include).then(|| value.clone()),
+            };
+            if let Some(projected) = projected {
+                out.insert(key.clone(), projected);
+            }
+        }
+        out
+    }
+
+    /// Returns a copy of this document containing only the fields whose keys appear in `keys`, in
+    /// the order of `keys`. Keys that are not present in this document are skipped, and keys
+    /// given more than once are only included once, at their first position.
+    ///
+    /// Unlike [`Document::apply_projection`], keys are matched exactly and never treated as
+    /// dot-notation paths.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "a": 1, "b": 2, "c": 3 };
+    /// assert_eq!(doc.select_fields(["c", "x", "a"]), doc! { "c": 3, "a": 1 });
+    /// ```
+    pub fn select_fields<'a>(&self, keys: impl IntoIterator<Item = &'a str>) -> Document {
+        let mut out = Document::new();
+        for key in keys {
+            if out.contains_key(key) {
+                continue;
+            }
+            if let Some(value) = self.get(key) {
+                out.insert(key, value.clone());
+            }
+        }
+        out
+    }
+
+    /// Returns a MongoDB update document that transforms this document into `new`.
+    ///
+    /// Fields that are present in `new` but missing from or different in this document are
+    /// listed under `$set`, and fields that are missing from `new` are listed under `$unset`.
+    /// Operators with no fields are omitted, so identical documents produce an empty update.
+    ///
+    /// The comparison is shallow: if an embedded document differs in any way, the whole of its new
+    /// value is set.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let old = doc! { "a": 1, "b": 2 };
+    /// let new = doc! { "a": 1, "b": 3, "c": 4 };
+    /// assert_eq!(old.diff(&new), doc! { "$set": { "b": 3, "c": 4 } });
+    /// assert_eq!(new.diff(&old), doc! { "$set": { "b": 2 }, "$unset": { "c": "" } });
+    /// ```
+    pub fn diff(&self, new: &Document) -> Document {
+        let mut set = Document::new();
+        for (key, value) in new {
+            if self.get(key) != Some(value) {
+                set.insert(key.clone(), value.clone());
+            }
+        }
+
+        let mut unset = Document::new();
+        for key in self.keys() {
+            if !new.contains_key(key) {
+                unset.insert(key.clone(), "");
+            }
+        }
+
+        let mut update = Document::new();
+        if !set.is_empty() {
+            update.insert("$set", set);
+        }
+        if !unset.is_empty() {
+            update.insert("$unset", unset);
+        }
+        update
+    }
+
+    /// Returns an iterator over every key present in either `a` or `b`, along with the value for
+    /// that key in each document, if any. The keys of `a` are yielded first, in order, followed by
+    /// the keys that are only present in `b`, in order.
+    ///
+    /// ```
+    /// use bson::{doc, Bson, Document};
+    ///
+    /// let a = doc! { "x": 1, "y": 2 };
+    /// let b = doc! { "z": 3, "y": 4 };
+    /// let pairs: Vec<_> = Document::zip(&a, &b).collect();
+    /// assert_eq!(
+    ///     pairs,
+    ///     [
+    ///         ("x", Some(&Bson::Int32(1)), None),
+    ///         ("y", Some(&Bson::Int32(2)), Some(&Bson::Int32(4))),
+    ///         ("z", None, Some(&Bson::Int32(3))),
+    ///     ]
+    /// );
+    /// ```
+    pub fn zip<'a>(
+        a: &'a Document,
+        b: &'a Document,
+    ) -> impl Iterator<Item = (&'a str, Option<&'a Bson>, Option<&'a Bson>)> {
+        let both = a
+            .iter()
+            .map(move |(key, value)| (key.as_str(), Some(value), b.get(key)));
+        let only_b = b
+            .iter()
+            .filter(move |(key, _)| !a.contains_key(key))
+            .map(|(key, value)| (key.as_str(), None, Some(value)));
+        both.chain(only_b)
+    }
+
     /// Returns the value for the given key if one is present and is of type
     /// [`ElementType::Double`].
     pub fn get_f64(&self, key: impl AsRef<str>) -> Result<f64> {
@@ -365,6 +856,62 @@ impl Document {
         .map_err(|e| e.with_key(key))
     }
 
+    /// Returns the elements of the array for the given key if one is present, is of type
+    /// [`ElementType::Array`], and contains only values of type [`ElementType::String`]. If an
+    /// element has a different type, the returned error includes its index.
+    ///
+    /// ```
+    /// use bson::doc;
+    ///
+    /// let doc = doc! { "tags": ["a", "b"], "mixed": ["a", 1] };
+    /// assert_eq!(doc.get_array_of_strings("tags")?, ["a", "b"]);
+    /// assert_eq!(doc.get_array_of_strings("mixed").unwrap_err().index, Some(1));
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn get_array_of_strings(&self, key: impl AsRef<str>) -> Result<Vec<&str>> {
+        self.get_array_of(key.as_ref(), ElementType::String, Bson::as_str)
+    }
+
+    /// Returns the elements of the array for the given key if one is present, is of type
+    /// [`ElementType::Array`], and contains only values of type [`ElementType::Int32`]. See
+    /// [`Document::get_array_of_strings`] for more details.
+    pub fn get_array_of_i32(&self, key: impl AsRef<str>) -> Result<Vec<i32>> {
+        self.get_array_of(key.as_ref(), ElementType::Int32, Bson::as_i32)
+    }
+
+    /// Returns the elements of the array for the given key if one is present, is of type
+    /// [`ElementType::Array`], and contains only values of type [`ElementType::Int64`]. See
+    /// [`Document::get_array_of_strings`] for more details.
+    pub fn get_array_of_i64(&self, key: impl AsRef<str>) -> Result<Vec<i64>> {
+        self.get_array_of(key.as_ref(), ElementType::Int64, Bson::as_i64)
+    }
+
+    /// Returns the elements of the array for the given key if one is present, is of type
+    /// [`ElementType::Array`], and contains only values of type [`ElementType::ObjectId`]. See
+    /// [`Document::get_array_of_strings`] for more details.
+    pub fn get_array_of_object_ids(&self, key: impl AsRef<str>) -> Result<Vec<ObjectId>> {
+        self.get_array_of(key.as_ref(), ElementType::ObjectId, Bson::as_object_id)
+    }
+
+    fn get_array_of<'a, T>(
+        &'a self,
+        key: &str,
+        expected: ElementType,
+        convert: impl Fn(&'a Bson) -> Option<T>,
+    ) -> Result<Vec<T>> {
+        self.get_array(key)?
+            .iter()
+            .enumerate()
+            .map(|(index, bson)| {
+                convert(bson).ok_or_else(|| {
+                    Error::value_access_unexpected_type(bson.element_type(), expected)
+                        .with_key(key)
+                        .with_index(index)
+                })
+            })
+            .collect()
+    }
+
     /// Returns a reference to the value for the given key if one is present and is of type
     /// [`ElementType::EmbeddedDocument`].
     pub fn get_document(&self, key: impl AsRef<str>) -> Result<&Document> {
@@ -642,6 +1189,13 @@ impl Document {
         }
     }
 
+    /// Returns an iterator over mutable references to the values in the document.
+    pub fn values_mut(&mut self) -> ValuesMut<'_> {
+        ValuesMut {
+            inner: self.inner.values_mut(),
+        }
+    }
+
     /// Returns the number of elements in the document.
     pub fn len(&self) -> usize {
         self.inner.len()
@@ -659,6 +1213,42 @@ impl Document {
         self.inner.insert(key.into(), val.into())
     }
 
+    /// Inserts the provided key-value pair into the document at the position that keeps the keys in
+    /// ascending order, assuming they already are. If a value is already present for the given key,
+    /// it will be overridden and returned.
+    ///
+    /// The position is found with a binary search, so if the keys are not already sorted the new
+    /// key's position is unspecified, and an existing entry that the search does not find is moved
+    /// to the position the search returned rather than being updated in place. Computes in
+    /// **O(n)** time (average).
+    ///
+    /// ```
+    /// use bson::Document;
+    ///
+    /// let mut doc = Document::new();
+    /// doc.insert_sorted("c", 3);
+    /// doc.insert_sorted("a", 1);
+    /// doc.insert_sorted("b", 2);
+    /// assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+    /// ```
+    pub fn insert_sorted<KT: Into<String>, BT: Into<Bson>>(
+        &mut self,
+        key: KT,
+        val: BT,
+    ) -> Option<Bson> {
+        let key = key.into();
+        match self.inner.binary_search_keys(&key) {
+            Ok(index) => Some(std::mem::replace(&mut self.inner[index], val.into())),
+            Err(index) => {
+                let (current, old) = self.inner.insert_full(key, val.into());
+                // an existing key that the search missed is removed from before `index`
+                let index = if current < index { index - 1 } else { index };
+                self.inner.move_index(current, index);
+                old
+            }
+        }
+    }
+
     /// Removes and returns the value that corresponds to the given key if present. Computes in
     /// **O(n)** time (average).
     pub fn remove(&mut self, key: impl AsRef<str>) -> Option<Bson> {
@@ -732,6 +1322,51 @@ impl Document {
         let raw = crate::raw::RawDocumentBuf::decode_from_reader(reader)?;
         raw.try_into()
     }
+
+    /// Serializes `value` into a [`Document`].
+    ///
+    /// This is equivalent to [`serialize_to_document`](crate::serialize_to_document).
+    ///
+    /// ```
+    /// # use serde::Serialize;
+    /// use bson::{doc, Document};
+    ///
+    /// #[derive(Serialize)]
+    /// struct Cat {
+    ///     name: String,
+    /// }
+    ///
+    /// let doc = Document::from_serde(&Cat { name: "Garfield".to_string() })?;
+    /// assert_eq!(doc, doc! { "name": "Garfield" });
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_serde<T: serde::Serialize + ?Sized>(value: &T) -> Result<Document> {
+        crate::serialize_to_document(value)
+    }
+
+    /// Deserializes a `T` from a copy of this [`Document`].
+    ///
+    /// This is equivalent to [`deserialize_from_document`](crate::deserialize_from_document), which
+    /// avoids the copy when the document is no longer needed.
+    ///
+    /// ```
+    /// # use serde::Deserialize;
+    /// use bson::doc;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Cat {
+    ///     name: String,
+    /// }
+    ///
+    /// let cat: Cat = doc! { "name": "Garfield" }.to_serde()?;
+    /// assert_eq!(cat.name, "Garfield");
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_serde<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        crate::deserialize_from_document(self.clone())
+    }
 }
 
 /// A view into a single entry in a document, which may either be vacant or occupied.
@@ -805,3 +1440,11 @@ impl Extend<(String, Bson)> for Document {
         }
     }
 }
+
+impl<'a> Extend<(&'a str, Bson)> for Document {
+    fn extend<T: IntoIterator<Item = (&'a str, Bson)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
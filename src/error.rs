@@ -105,6 +105,11 @@ pub enum ErrorKind {
         kind: ObjectIdErrorKind,
     },
 
+    /// An error related to the [`Regex`](crate::Regex) type occurred.
+    #[error("A Regex-related error occurred")]
+    #[non_exhaustive]
+    Regex {},
+
     /// A general error occurred during serialization. This variant is constructed in the
     /// [`serde::ser::Error`] implementation for the [`Error`](struct@Error) type.
     #[cfg(feature = "serde")]
@@ -160,6 +165,12 @@ impl From<ErrorKind> for Error {
     }
 }
 
+impl From<std::convert::Infallible> for Error {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(value: std::io::Error) -> Self {
         Error::from(ErrorKind::Io {}).with_message(value)
@@ -218,6 +229,10 @@ impl Error {
         Self::from(ErrorKind::DateTime {}).with_message(message)
     }
 
+    pub(crate) fn regex(message: impl ToString) -> Self {
+        Self::from(ErrorKind::Regex {}).with_message(message)
+    }
+
     #[cfg(feature = "serde")]
     pub(crate) fn serialization(message: impl ToString) -> Self {
         Self::from(ErrorKind::Serialization {}).with_message(message)
@@ -11,6 +11,7 @@ use crate::{
     DbPointer,
     Document,
     JavaScriptCodeWithScope,
+    RawDocumentBuf,
     Regex,
     Timestamp,
 };
@@ -172,6 +173,32 @@ impl TryFrom<serde_json::Map<String, serde_json::Value>> for Document {
     }
 }
 
+impl RawDocumentBuf {
+    /// Parses a string containing a relaxed or canonical
+    /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) object
+    /// into a [`RawDocumentBuf`].
+    ///
+    /// ```
+    /// # use bson::{oid::ObjectId, RawDocumentBuf};
+    /// let json = r#"{ "_id": { "$oid": "507f1f77bcf86cd799439011" } }"#;
+    /// let doc = RawDocumentBuf::from_json_str(json)?;
+    /// assert_eq!(doc.get_object_id("_id")?, ObjectId::parse_str("507f1f77bcf86cd799439011")?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        let obj: serde_json::Map<String, Value> = serde_json::from_str(s)?;
+        RawDocumentBuf::from_document(Document::try_from(obj)?)
+    }
+
+    /// Reads a relaxed or canonical
+    /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) object
+    /// from `reader` and parses it into a [`RawDocumentBuf`].
+    pub fn from_reader_json<R: std::io::Read>(reader: R) -> Result<Self> {
+        let obj: serde_json::Map<String, Value> = serde_json::from_reader(reader)?;
+        RawDocumentBuf::from_document(Document::try_from(obj)?)
+    }
+}
+
 /// Converts [`Bson`] into a [`serde_json::Value`] in relaxed
 /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/).
 impl From<Bson> for Value {
@@ -181,6 +208,29 @@ impl From<Bson> for Value {
 }
 
 impl Bson {
+    /// Parses a string of relaxed or canonical
+    /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) into
+    /// a [`Bson`] value.
+    ///
+    /// ```
+    /// # use bson::{oid::ObjectId, Bson};
+    /// let bson = Bson::from_str_json(r#"{ "$oid": "507f1f77bcf86cd799439011" }"#)?;
+    /// assert_eq!(bson, Bson::ObjectId(ObjectId::parse_str("507f1f77bcf86cd799439011")?));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_str_json(s: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(s)?;
+        value.try_into()
+    }
+
+    /// Reads relaxed or canonical
+    /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/) from
+    /// `reader` and parses it into a [`Bson`] value.
+    pub fn from_reader_json<R: std::io::Read>(reader: R) -> Result<Self> {
+        let value: Value = serde_json::from_reader(reader)?;
+        value.try_into()
+    }
+
     /// Converts this value into a [`serde_json::Value`] in relaxed
     /// [extended JSON](https://www.mongodb.com/docs/manual/reference/mongodb-extended-json/)
     /// format.
@@ -379,7 +379,16 @@
 #[doc(inline)]
 pub use self::{
     binary::Binary,
-    bson::{Array, Bson, DbPointer, Document, JavaScriptCodeWithScope, Regex, Timestamp},
+    bson::{
+        Array,
+        Bson,
+        DbPointer,
+        Document,
+        JavaScriptCodeWithScope,
+        Regex,
+        Timestamp,
+        ToBson,
+    },
     datetime::DateTime,
     decimal128::Decimal128,
     raw::{
@@ -409,6 +418,7 @@ pub use self::{
         Deserializer,
     },
     ser::{
+        bson_size,
         serialize_to_bson,
         serialize_to_document,
         serialize_to_raw_document_buf,
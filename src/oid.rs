@@ -168,6 +168,9 @@ impl ObjectId {
     }
 
     /// Retrieves the timestamp from an [`ObjectId`].
+    ///
+    /// The timestamp embedded in an [`ObjectId`] only has second precision, so the returned
+    /// [`DateTime`](crate::DateTime) never has any sub-second component.
     pub fn timestamp(&self) -> crate::DateTime {
         let mut buf = [0; 4];
         buf.copy_from_slice(&self.id[0..4]);
@@ -177,6 +180,60 @@ impl ObjectId {
         crate::DateTime::from_millis(seconds_since_epoch as i64 * 1000)
     }
 
+    /// Retrieves the timestamp from an [`ObjectId`] as the number of milliseconds since the Unix
+    /// epoch. This is always a whole number of seconds; see [`ObjectId::timestamp`].
+    pub fn timestamp_millis(&self) -> i64 {
+        self.timestamp().timestamp_millis()
+    }
+
+    /// Retrieves the machine identifier from an [`ObjectId`], i.e. the three bytes following the
+    /// timestamp.
+    ///
+    /// This is only meaningful for ObjectIds in the legacy format, which embedded a machine
+    /// identifier and process ID. ObjectIds generated by current drivers, including by
+    /// [`ObjectId::new`], use a random value in those bytes instead.
+    pub fn machine_id(&self) -> [u8; 3] {
+        let mut buf = [0; 3];
+        buf.copy_from_slice(&self.id[PROCESS_ID_OFFSET..(PROCESS_ID_OFFSET + 3)]);
+        buf
+    }
+
+    /// Retrieves the big-endian process ID from an [`ObjectId`], i.e. the two bytes following the
+    /// machine identifier. See [`ObjectId::machine_id`] for caveats.
+    pub fn process_id(&self) -> u16 {
+        let mut buf = [0; 2];
+        buf.copy_from_slice(&self.id[(PROCESS_ID_OFFSET + 3)..COUNTER_OFFSET]);
+        u16::from_be_bytes(buf)
+    }
+
+    /// Returns an [`ObjectId`] identical to this one except that its 3-byte counter is incremented
+    /// by one, or `None` if the counter is already at its maximum value.
+    ///
+    /// The result always compares greater than `self`, which makes this useful for generating
+    /// ordered sequences of ObjectIds with the same timestamp in tests and benchmarks.
+    ///
+    /// ```
+    /// use bson::oid::ObjectId;
+    ///
+    /// let oid = ObjectId::from_parts(0, [0; 5], [0, 0, 1]);
+    /// let next = oid.sequential_after().unwrap();
+    /// assert_eq!(next, ObjectId::from_parts(0, [0; 5], [0, 0, 2]));
+    /// assert!(oid < next);
+    ///
+    /// assert!(ObjectId::from_parts(0, [0; 5], [0xFF; 3]).sequential_after().is_none());
+    /// ```
+    pub fn sequential_after(&self) -> Option<ObjectId> {
+        let mut buf = [0; 4];
+        buf[1..].copy_from_slice(&self.id[COUNTER_OFFSET..]);
+        let counter = u32::from_be_bytes(buf) as usize;
+        if counter == MAX_U24 {
+            return None;
+        }
+        let mut id = self.id;
+        id[COUNTER_OFFSET..].copy_from_slice(&((counter + 1) as u32).to_be_bytes()[1..]);
+        Some(ObjectId { id })
+    }
+
     /// Returns the raw byte representation of an ObjectId.
     pub const fn bytes(&self) -> [u8; 12] {
         self.id
@@ -349,4 +406,14 @@ mod test {
             id.timestamp().to_time_0_3()
         );
     }
+
+    #[test]
+    fn test_timestamp_millis() {
+        let id = super::ObjectId::parse_str("7FFFFFFF0000000000000000").unwrap();
+        assert_eq!(id.timestamp_millis(), i32::MAX as i64 * 1000);
+
+        let id = super::ObjectId::new();
+        assert_eq!(id.timestamp_millis(), id.timestamp().timestamp_millis());
+        assert_eq!(id.timestamp_millis() % 1000, 0);
+    }
 }
@@ -94,7 +94,8 @@ impl RawArray {
 
     /// Convert this borrowed [`RawArray`] into an owned [`RawArrayBuf`].
     ///
-    /// This involves a traversal of the array to count the values.
+    /// The underlying bytes are copied as-is rather than being decoded and re-encoded, but this
+    /// still involves a traversal of the array to count the values.
     pub fn to_raw_array_buf(&self) -> RawArrayBuf {
         RawArrayBuf::from_raw_document_buf(self.doc.to_raw_document_buf())
     }
@@ -104,6 +105,29 @@ impl RawArray {
         self.into_iter().nth(index).transpose()
     }
 
+    /// Gets the element type and encoded value bytes at the given index without parsing the value.
+    /// See [`RawDocument::iter_raw_values`] for the format of the bytes.
+    ///
+    /// ```
+    /// use bson::{rawdoc, spec::ElementType};
+    ///
+    /// let doc = rawdoc! { "x": [1, "two"] };
+    /// let array = doc.get_array("x")?;
+    /// assert_eq!(
+    ///     array.get_raw_bytes_at(0)?,
+    ///     Some((ElementType::Int32, &1i32.to_le_bytes()[..]))
+    /// );
+    /// assert_eq!(array.get_raw_bytes_at(2)?, None);
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn get_raw_bytes_at(&self, index: usize) -> RawResult<Option<(ElementType, &[u8])>> {
+        self.doc
+            .iter_raw_values()
+            .nth(index)
+            .transpose()
+            .map(|value| value.map(|(_, element_type, bytes)| (element_type, bytes)))
+    }
+
     fn get_with<'a, T>(
         &'a self,
         index: usize,
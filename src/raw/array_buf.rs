@@ -188,7 +188,20 @@ impl TryFrom<&crate::Array> for RawArrayBuf {
     type Error = crate::error::Error;
 
     fn try_from(value: &crate::Array) -> Result<Self, Self::Error> {
-        Self::try_from(value.clone())
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl TryFrom<&[crate::Bson]> for RawArrayBuf {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &[crate::Bson]) -> Result<Self, Self::Error> {
+        let mut tmp = RawArrayBuf::new();
+        for val in value {
+            let raw: super::RawBson = val.clone().try_into()?;
+            tmp.push(raw);
+        }
+        Ok(tmp)
     }
 }
 
@@ -319,6 +319,12 @@ impl RawBson {
             }),
         }
     }
+
+    /// Convert this [`RawBson`] to the equivalent [`Bson`], returning an error if the value or any
+    /// nested value is not valid BSON.
+    pub fn to_bson(&self) -> Result<Bson> {
+        self.clone().try_into()
+    }
 }
 
 impl From<i32> for RawBson {
@@ -533,8 +539,8 @@ impl<'de> serde::Deserialize<'de> for RawJavaScriptCodeWithScope {
         match RawBson::deserialize(deserializer)? {
             RawBson::JavaScriptCodeWithScope(b) => Ok(b),
             c => Err(serde::de::Error::custom(format!(
-                "expected CodeWithScope, but got {:?} instead",
-                c
+                "expected CodeWithScope, but got {} instead",
+                c.element_type().type_name()
             ))),
         }
     }
@@ -292,6 +292,12 @@ impl<'a> RawBsonRef<'a> {
         }
     }
 
+    /// Convert this [`RawBsonRef`] to the equivalent [`Bson`], returning an error if the value or
+    /// any nested value is not valid BSON.
+    pub fn to_bson(self) -> Result<Bson> {
+        self.try_into()
+    }
+
     #[inline]
     pub(crate) fn append_to(self, dest: &mut Vec<u8>) {
         match self {
@@ -536,8 +542,8 @@ impl<'de: 'a, 'a> serde::Deserialize<'de> for RawBinaryRef<'a> {
         match RawBsonRef::deserialize(deserializer)? {
             RawBsonRef::Binary(b) => Ok(b),
             c => Err(serde::de::Error::custom(format!(
-                "expected binary, but got {:?} instead",
-                c
+                "expected binary, but got {} instead",
+                c.element_type().type_name()
             ))),
         }
     }
@@ -618,8 +624,8 @@ impl<'de: 'a, 'a> serde::Deserialize<'de> for RawRegexRef<'a> {
         match RawBsonRef::deserialize(deserializer)? {
             RawBsonRef::RegularExpression(b) => Ok(b),
             c => Err(serde::de::Error::custom(format!(
-                "expected Regex, but got {:?} instead",
-                c
+                "expected Regex, but got {} instead",
+                c.element_type().type_name()
             ))),
         }
     }
@@ -678,8 +684,8 @@ impl<'de: 'a, 'a> serde::Deserialize<'de> for RawJavaScriptCodeWithScopeRef<'a>
         match RawBsonRef::deserialize(deserializer)? {
             RawBsonRef::JavaScriptCodeWithScope(b) => Ok(b),
             c => Err(serde::de::Error::custom(format!(
-                "expected CodeWithScope, but got {:?} instead",
-                c
+                "expected CodeWithScope, but got {} instead",
+                c.element_type().type_name()
             ))),
         }
     }
@@ -720,8 +726,8 @@ impl<'de: 'a, 'a> serde::Deserialize<'de> for RawDbPointerRef<'a> {
         match RawBsonRef::deserialize(deserializer)? {
             RawBsonRef::DbPointer(b) => Ok(b),
             c => Err(serde::de::Error::custom(format!(
-                "expected DbPointer, but got {:?} instead",
-                c
+                "expected DbPointer, but got {} instead",
+                c.element_type().type_name()
             ))),
         }
     }
@@ -233,6 +233,18 @@ pub use cstr;
 ///
 /// Like `CStr`, this differs from [`std::ffi::CString`] in that it is required to be valid UTF-8,
 /// and does not include the nul terminator in the buffer.
+///
+/// The [`TryFrom`] conversions check for interior nul bytes and return an error if any are found:
+/// ```
+/// use bson::raw::CString;
+///
+/// let key = CString::try_from("key")?;
+/// assert_eq!(key.as_str(), "key");
+///
+/// let err = CString::try_from("invalid\0key".to_string()).unwrap_err();
+/// assert!(err.to_string().contains("interior null"));
+/// # Ok::<(), bson::error::Error>(())
+/// ```
 #[derive(Clone, Eq)]
 #[repr(transparent)]
 pub struct CString {
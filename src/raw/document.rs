@@ -188,6 +188,33 @@ impl RawDocument {
         RawIter::new(self)
     }
 
+    /// Gets an iterator over the elements in the [`RawDocument`] that yields the key, element type,
+    /// and encoded value bytes of each element without parsing the values.
+    ///
+    /// The value bytes are exactly as they appear in the document after the key, e.g. the 4-byte
+    /// length prefix, contents, and null terminator of a string.
+    ///
+    /// ```
+    /// use bson::{rawdoc, spec::ElementType};
+    ///
+    /// let doc = rawdoc! { "a": 1 };
+    /// let (key, element_type, bytes) = doc.iter_raw_values().next().unwrap()?;
+    /// assert_eq!(key, "a");
+    /// assert_eq!(element_type, ElementType::Int32);
+    /// assert_eq!(bytes, 1i32.to_le_bytes());
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn iter_raw_values(&self) -> impl Iterator<Item = Result<(&str, ElementType, &[u8])>> + '_ {
+        self.iter_elements().map(|element| {
+            let element = element?;
+            Ok((
+                element.key().as_str(),
+                element.element_type(),
+                element.slice(),
+            ))
+        })
+    }
+
     fn get_with<'a, T>(
         &'a self,
         key: impl AsRef<str>,
@@ -492,6 +519,22 @@ impl RawDocument {
         self.as_bytes().len() == MIN_BSON_DOCUMENT_SIZE as usize
     }
 
+    /// Returns the number of elements in this document.
+    ///
+    /// This walks the element headers to find the boundaries between elements, but does not
+    /// parse or validate the values themselves. An error is returned if the document is malformed.
+    ///
+    /// ```
+    /// use bson::rawdoc;
+    /// let doc = rawdoc! { "a": 1, "b": { "c": "d" } };
+    /// assert_eq!(doc.len()?, 2);
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn len(&self) -> Result<usize> {
+        self.iter_elements()
+            .try_fold(0, |count, element| element.map(|_| count + 1))
+    }
+
     pub(crate) fn cstring_bytes_at(&self, start_at: usize) -> RawResult<&[u8]> {
         let buf = &self.as_bytes()[start_at..];
 
@@ -1,11 +1,12 @@
 use std::{
     borrow::{Borrow, Cow},
-    convert::TryFrom,
+    convert::{TryFrom, TryInto},
     ops::Deref,
 };
 
 use crate::{
-    raw::{CStr, MIN_BSON_DOCUMENT_SIZE},
+    error::Error,
+    raw::{CStr, CString, MIN_BSON_DOCUMENT_SIZE},
     Document,
 };
 
@@ -160,6 +161,9 @@ impl RawDocumentBuf {
 
     /// Return the contained data as a `Vec<u8>`
     ///
+    /// This consumes the buffer without copying, so the bytes can be written out directly or
+    /// extended with further data (e.g. when assembling a message containing several documents).
+    ///
     /// ```
     /// use bson::{doc, raw::RawDocumentBuf};
     ///
@@ -171,6 +175,49 @@ impl RawDocumentBuf {
         self.data
     }
 
+    /// Convert this document into a [`RawArrayBuf`](crate::RawArrayBuf) without copying, provided
+    /// that its keys are the consecutive indices `"0"`, `"1"`, `"2"`, etc. that BSON uses to encode
+    /// arrays. An error is returned if any key is out of sequence or if the document is malformed.
+    ///
+    /// ```
+    /// use bson::rawdoc;
+    ///
+    /// let array = rawdoc! { "0": "a", "1": "b" }.into_raw_array_buf()?;
+    /// assert_eq!(array.get_str(1)?, "b");
+    /// assert!(rawdoc! { "1": "a" }.into_raw_array_buf().is_err());
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn into_raw_array_buf(self) -> Result<crate::RawArrayBuf> {
+        for (index, element) in self.iter_elements().enumerate() {
+            let key = element?.key();
+            if key.as_str() != index.to_string() {
+                return Err(Error::malformed_bytes(format!(
+                    "expected array index {} but found key {:?}",
+                    index,
+                    key.as_str()
+                )));
+            }
+        }
+        Ok(crate::RawArrayBuf::from_raw_document_buf(self))
+    }
+
+    /// Convert this into a [`Document`], returning an error if invalid BSON is encountered.
+    ///
+    /// The values are parsed directly from this document's buffer, which is not copied
+    /// beforehand and is freed once the conversion completes; the resulting [`Document`] owns its
+    /// contents independently.
+    ///
+    /// ```
+    /// use bson::{doc, rawdoc};
+    ///
+    /// let doc = rawdoc! { "a": 1, "b": { "c": "d" } }.into_document()?;
+    /// assert_eq!(doc, doc! { "a": 1, "b": { "c": "d" } });
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn into_document(self) -> Result<Document> {
+        Document::try_from(self.as_ref())
+    }
+
     /// Append a key value pair to the end of the document without checking to see if
     /// the key already exists.
     ///
@@ -209,6 +256,125 @@ impl RawDocumentBuf {
         let key = key.as_ref();
         value.bind(|value_ref| raw_writer::RawWriter::new(&mut self.data).append(key, value_ref));
     }
+
+    /// Sets the value for the given key, returning whether an existing field was replaced. Any
+    /// existing fields with the key are removed and the new value is appended to the end of the
+    /// document, so unlike [`Document::insert`], a replaced field does not keep its position.
+    ///
+    /// An error is returned and the document is left unchanged if the key contains a null byte or
+    /// the document is malformed.
+    ///
+    /// ```
+    /// use bson::rawdoc;
+    ///
+    /// let mut doc = rawdoc! { "a": 1, "b": 2 };
+    /// assert!(doc.try_set("a", 3)?);
+    /// assert!(!doc.try_set("c", 4)?);
+    /// assert_eq!(doc, rawdoc! { "b": 2, "a": 3, "c": 4 });
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn try_set(&mut self, key: &str, value: impl BindRawBsonRef) -> Result<bool> {
+        let key: &CStr = key.try_into()?;
+        let mut existing = Vec::new();
+        for element in self.iter_elements() {
+            let element = element?;
+            if element.key() == key {
+                existing.push(element.element_range());
+            }
+        }
+
+        for range in existing.iter().rev() {
+            self.data.drain(range.clone());
+        }
+        if !existing.is_empty() {
+            let new_len = (self.data.len() as i32).to_le_bytes();
+            self.data[0..4].copy_from_slice(&new_len);
+        }
+        self.append(key, value);
+        Ok(!existing.is_empty())
+    }
+
+    /// Append each key value pair yielded by `iter` to the end of the document, without checking
+    /// to see if the keys already exist. See [`RawDocumentBuf::append`] for more details.
+    ///
+    /// Keys can be any type that can be converted into a [`CString`], such as `&str` or
+    /// `String`. If any key fails to convert (e.g. because it contains a null byte), an error is
+    /// returned and the document is left unchanged.
+    ///
+    /// ```
+    /// use bson::rawdoc;
+    ///
+    /// let mut doc = rawdoc! { "a": 1 };
+    /// doc.try_extend([("b", 2), ("c", 3)])?;
+    /// assert_eq!(doc, rawdoc! { "a": 1, "b": 2, "c": 3 });
+    ///
+    /// assert!(doc.try_extend([("d", 4), ("invalid\0key", 5)]).is_err());
+    /// assert_eq!(doc, rawdoc! { "a": 1, "b": 2, "c": 3 });
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn try_extend<K, V, I>(&mut self, iter: I) -> Result<()>
+    where
+        K: TryInto<CString>,
+        K::Error: Into<Error>,
+        V: BindRawBsonRef,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let original_len = self.data.len();
+        for (key, value) in iter {
+            let key = match key.try_into() {
+                Ok(key) => key,
+                Err(e) => {
+                    self.data.truncate(original_len - 1);
+                    self.data.push(0);
+                    let len = (original_len as i32).to_le_bytes();
+                    self.data[0..4].copy_from_slice(&len);
+                    return Err(e.into());
+                }
+            };
+            self.append(key, value);
+        }
+        Ok(())
+    }
+
+    /// Append all of the elements of `other` to the end of this document.
+    ///
+    /// Like [`RawDocumentBuf::append`], this does not check for duplicate keys; any keys present in
+    /// both documents will appear twice in the result. Because lookups such as
+    /// [`RawDocument::get`] return the first matching element, the values already present in
+    /// `self` take precedence over those from `other`.
+    ///
+    /// ```
+    /// use bson::{doc, rawdoc};
+    ///
+    /// let mut doc = rawdoc! { "a": 1, "b": 2 };
+    /// doc.merge(&rawdoc! { "b": 3, "c": 4 });
+    ///
+    /// assert_eq!(doc.get_i32("b")?, 2);
+    /// assert_eq!(doc.get_i32("c")?, 4);
+    /// assert_eq!(doc.iter().count(), 4);
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn merge(&mut self, other: &RawDocument) {
+        // Both documents are framed by a four byte length prefix and a trailing null byte, so the
+        // elements of `other` can be spliced in directly before the trailing null of `self`.
+        let elements = &other.as_bytes()[4..other.as_bytes().len() - 1];
+        if elements.is_empty() {
+            return;
+        }
+        self.data.pop();
+        self.data.extend_from_slice(elements);
+        self.data.push(0);
+        let new_len = (self.data.len() as i32).to_le_bytes();
+        self.data[0..4].copy_from_slice(&new_len);
+    }
+
+    /// Append all of the elements of `other` to the end of this document and return the result.
+    ///
+    /// See [`RawDocumentBuf::merge`] for details on how duplicate keys are handled.
+    pub fn merge_owned(mut self, other: RawDocumentBuf) -> RawDocumentBuf {
+        self.merge(&other);
+        self
+    }
 }
 
 impl<K: AsRef<CStr>, B: BindRawBsonRef> FromIterator<(K, B)> for RawDocumentBuf {
@@ -330,6 +330,12 @@ impl<'a> RawElement<'a> {
         self.slice_bounds(self.start_at, self.size)
     }
 
+    /// The range of the whole element within its document, including the element type byte and
+    /// the key.
+    pub(crate) fn element_range(&self) -> std::ops::Range<usize> {
+        (self.start_at - self.key.len() - 2)..(self.start_at + self.size)
+    }
+
     fn slice_bounds(&self, start_at: usize, size: usize) -> &'a [u8] {
         &self.doc.as_bytes()[start_at..(start_at + size)]
     }
@@ -146,8 +146,8 @@ impl<'a, 'de: 'a> Deserialize<'de> for OwnedOrBorrowedRawDocument<'a> {
             }
 
             o => Err(SerdeError::custom(format!(
-                "expected raw document, instead got {:?}",
-                o
+                "expected raw document, instead got {}",
+                o.as_ref().element_type().type_name()
             ))),
         }
     }
@@ -197,8 +197,8 @@ impl<'a, 'de: 'a> Deserialize<'de> for OwnedOrBorrowedRawArray<'a> {
             }
 
             o => Err(SerdeError::custom(format!(
-                "expected raw array, instead got {:?}",
-                o
+                "expected raw array, instead got {}",
+                o.as_ref().element_type().type_name()
             ))),
         }
     }
@@ -5,7 +5,7 @@ use super::*;
 use crate::{
     doc,
     oid::ObjectId,
-    spec::BinarySubtype,
+    spec::{BinarySubtype, ElementType},
     Binary,
     Bson,
     DateTime,
@@ -469,6 +469,224 @@ fn into_bson_conversion() {
     );
 }
 
+#[test]
+fn to_bson() {
+    let doc = doc! {
+        "double": 2.5,
+        "string": "hello",
+        "array": [1, "two", { "three": 3 }],
+        "document": { "a": { "b": true } },
+        "boolean": false,
+        "null": null,
+        "regex": Regex::from_strings("^a", "mi").unwrap(),
+        "code": Bson::JavaScriptCode("x + 1".to_string()),
+        "code_w_scope": Bson::JavaScriptCodeWithScope(crate::JavaScriptCodeWithScope {
+            code: "x".to_string(),
+            scope: doc! { "x": 1 },
+        }),
+        "i32": 1,
+        "i64": 2_i64,
+        "timestamp": Timestamp { time: 1, increment: 2 },
+        "binary": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+        "object_id": ObjectId::from_bytes([1; 12]),
+        "datetime": DateTime::from_millis(1_000),
+        "symbol": Bson::Symbol("sym".to_string()),
+        "decimal128": Decimal128::from_bytes([2; 16]),
+        "undefined": Bson::Undefined,
+        "max_key": Bson::MaxKey,
+        "min_key": Bson::MinKey,
+        "db_pointer": Bson::DbPointer(crate::DbPointer {
+            namespace: "db.coll".to_string(),
+            id: ObjectId::from_bytes([3; 12]),
+        }),
+    };
+    let rawdoc = RawDocumentBuf::from_document(&doc).unwrap();
+    for element in &rawdoc {
+        let (key, value) = element.unwrap();
+        let expected = doc.get(key.as_str()).unwrap();
+        assert_eq!(&value.to_bson().unwrap(), expected, "{}", key);
+        assert_eq!(&value.to_raw_bson().to_bson().unwrap(), expected, "{}", key);
+    }
+    assert_eq!(
+        RawBsonRef::Document(&rawdoc).to_bson().unwrap(),
+        Bson::Document(doc)
+    );
+}
+
+#[test]
+fn cstring_validation() {
+    let key = CString::try_from("valid key").unwrap();
+    assert_eq!(key.as_str(), "valid key");
+    let key = CString::try_from("valid key".to_string()).unwrap();
+    assert_eq!(key.as_str(), "valid key");
+
+    let empty = CString::try_from("").unwrap();
+    assert!(empty.is_empty());
+
+    for invalid in ["\0", "embedded\0null", "trailing\0"] {
+        let err = CString::try_from(invalid).unwrap_err();
+        assert!(err.to_string().contains("interior null"), "{}", err);
+        CString::try_from(invalid.to_string()).unwrap_err();
+        <&CStr>::try_from(invalid).unwrap_err();
+    }
+}
+
+#[test]
+fn try_extend() {
+    let mut doc = rawdoc! { "a": 1 };
+    doc.try_extend(std::iter::empty::<(&str, i32)>()).unwrap();
+    assert_eq!(doc, rawdoc! { "a": 1 });
+
+    doc.try_extend(vec![
+        ("b".to_string(), RawBson::Int32(2)),
+        ("c".to_string(), RawBson::String("three".to_string())),
+        ("d".to_string(), RawBson::Boolean(true)),
+    ])
+    .unwrap();
+    assert_eq!(doc, rawdoc! { "a": 1, "b": 2, "c": "three", "d": true });
+
+    doc.try_extend([(CString::try_from("e").unwrap(), 5)])
+        .unwrap();
+    assert_eq!(doc.get_i32("e").unwrap(), 5);
+
+    let before = doc.clone();
+    let err = doc.try_extend([("f", 6), ("bad\0key", 7)]).unwrap_err();
+    assert!(
+        matches!(err.kind, crate::error::ErrorKind::MalformedBytes { .. }),
+        "{:?}",
+        err
+    );
+    assert_eq!(doc, before);
+}
+
+#[test]
+fn merge() {
+    let mut doc = rawdoc! { "a": 1, "b": "two" };
+    doc.merge(&RawDocumentBuf::new());
+    assert_eq!(doc, rawdoc! { "a": 1, "b": "two" });
+
+    let mut empty = RawDocumentBuf::new();
+    empty.merge(&doc);
+    assert_eq!(empty, doc);
+
+    doc.merge(&rawdoc! { "b": 3, "c": { "d": true } });
+    let keys: Vec<_> = doc
+        .iter()
+        .map(|elem| elem.unwrap().0.as_str().to_string())
+        .collect();
+    assert_eq!(keys, ["a", "b", "b", "c"]);
+    assert_eq!(doc.get_str("b").unwrap(), "two");
+    assert!(doc.get_document("c").unwrap().get_bool("d").unwrap());
+    assert_eq!(
+        RawDocumentBuf::decode_from_bytes(doc.as_bytes().to_vec()).unwrap(),
+        doc
+    );
+
+    let large: RawDocumentBuf = (0..1000)
+        .map(|i| (CString::try_from(format!("key{}", i)).unwrap(), i))
+        .collect();
+    let merged = rawdoc! { "first": true }.merge_owned(large.clone());
+    assert_eq!(merged.iter().count(), 1001);
+    assert_eq!(merged.get_i32("key999").unwrap(), 999);
+    assert_eq!(
+        merged.as_bytes().len(),
+        large.as_bytes().len() + rawdoc! { "first": true }.as_bytes().len() - 5
+    );
+}
+
+#[test]
+fn try_set() {
+    let mut doc = RawDocumentBuf::new();
+    assert!(!doc.try_set("a", 1).unwrap());
+    assert_eq!(doc, rawdoc! { "a": 1 });
+
+    assert!(!doc.try_set("b", "two").unwrap());
+    assert!(!doc.try_set("c", rawdoc! { "d": true }).unwrap());
+    assert_eq!(doc, rawdoc! { "a": 1, "b": "two", "c": { "d": true } });
+
+    // replaced fields are moved to the end, and may change type
+    assert!(doc.try_set("a", "one").unwrap());
+    assert_eq!(doc, rawdoc! { "b": "two", "c": { "d": true }, "a": "one" });
+    assert!(doc.try_set("c", 3_i64).unwrap());
+    assert_eq!(doc, rawdoc! { "b": "two", "a": "one", "c": 3_i64 });
+    assert_eq!(
+        RawDocumentBuf::decode_from_bytes(doc.as_bytes().to_vec()).unwrap(),
+        doc
+    );
+
+    // every duplicate of the key is removed
+    let mut doc = rawdoc! { "a": 1, "b": 2 };
+    doc.merge(&rawdoc! { "a": 3 });
+    assert!(doc.try_set("a", 4).unwrap());
+    assert_eq!(doc, rawdoc! { "b": 2, "a": 4 });
+
+    assert!(doc.try_set("invalid\0key", 5).is_err());
+    assert_eq!(doc, rawdoc! { "b": 2, "a": 4 });
+}
+
+#[test]
+fn raw_array_buf_from_bson_slice() {
+    let empty: &[Bson] = &[];
+    let array = RawArrayBuf::try_from(empty).unwrap();
+    assert!(array.is_empty());
+    assert_eq!(array, RawArrayBuf::new());
+
+    let values = vec![
+        Bson::Int32(1),
+        Bson::String("two".to_string()),
+        Bson::Document(doc! { "three": 3.0 }),
+        Bson::Array(vec![Bson::Boolean(true), Bson::Null]),
+    ];
+    let array = RawArrayBuf::try_from(values.as_slice()).unwrap();
+    assert_eq!(array, RawArrayBuf::try_from(&values).unwrap());
+    assert_eq!(array.get_i32(0).unwrap(), 1);
+    assert_eq!(array.get_str(1).unwrap(), "two");
+    assert_eq!(
+        array.get_document(2).unwrap().get_f64("three").unwrap(),
+        3.0
+    );
+    assert_eq!(array.get_array(3).unwrap().into_iter().count(), 2);
+
+    let owned = array.as_ref().to_owned();
+    assert_eq!(owned, array);
+    assert_eq!(owned.as_bytes(), array.as_bytes());
+
+    let mut invalid = crate::Document::new();
+    invalid.insert("bad\0key", 1);
+    let values = [Bson::Int32(1), Bson::Document(invalid)];
+    assert!(RawArrayBuf::try_from(&values[..]).is_err());
+}
+
+#[cfg(feature = "serde_json-1")]
+#[test]
+fn from_json_str() {
+    let canonical = r#"{
+        "_id": { "$oid": "507f1f77bcf86cd799439011" },
+        "created": { "$date": { "$numberLong": "1590972160292" } },
+        "count": { "$numberInt": "5" }
+    }"#;
+    let expected = rawdoc! {
+        "_id": ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap(),
+        "created": DateTime::from_millis(1_590_972_160_292),
+        "count": 5,
+    };
+    assert_eq!(RawDocumentBuf::from_json_str(canonical).unwrap(), expected);
+    assert_eq!(
+        RawDocumentBuf::from_reader_json(canonical.as_bytes()).unwrap(),
+        expected
+    );
+
+    let relaxed = r#"{ "a": 1, "b": 5000000000, "c": [1.5, { "d": "e" }] }"#;
+    assert_eq!(
+        RawDocumentBuf::from_json_str(relaxed).unwrap(),
+        rawdoc! { "a": 1, "b": 5_000_000_000_i64, "c": [1.5, { "d": "e" }] }
+    );
+
+    assert!(RawDocumentBuf::from_json_str("{ not json").is_err());
+    assert!(RawDocumentBuf::from_json_str("[1, 2]").is_err());
+    assert!(RawDocumentBuf::from_json_str(r#"{ "a": { "$numberLong": 5 } }"#).is_err());
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn fuzz_oom() {
@@ -508,3 +726,170 @@ proptest! {
         prop_assert_eq!(doc, roundtrip);
     }
 }
+
+#[test]
+fn into_bytes_decodes() {
+    let rawdoc = rawdoc! { "a": 1, "b": { "c": "d" } };
+    let expected = rawdoc.to_document().unwrap();
+
+    let mut bytes = rawdoc.into_bytes();
+    bytes.extend_from_slice(b"trailing");
+
+    let mut reader = bytes.as_slice();
+    let doc = crate::Document::decode_from_reader(&mut reader).unwrap();
+    assert_eq!(doc, expected);
+    assert_eq!(reader, b"trailing");
+}
+
+#[test]
+fn len() {
+    assert_eq!(rawdoc! {}.len().unwrap(), 0);
+
+    let single = rawdoc! { "a": { "b": [1, 2, 3] } };
+    assert_eq!(single.len().unwrap(), 1);
+    assert_eq!(single.len().unwrap(), single.iter().count());
+
+    let mut ten = RawDocumentBuf::new();
+    for i in 0..10 {
+        ten.append(CString::try_from(i.to_string()).unwrap(), i);
+    }
+    assert_eq!(ten.len().unwrap(), 10);
+    assert_eq!(ten.len().unwrap(), ten.iter().count());
+
+    // a string whose length prefix runs past the end of the document
+    let mut bytes = rawdoc! { "a": "b" }.into_bytes();
+    bytes[7] = 100;
+    let doc = RawDocument::decode_from_bytes(&bytes).unwrap();
+    assert!(doc.len().is_err());
+}
+
+#[test]
+fn iter_raw_values() {
+    let doc = rawdoc! { "int": 5, "str": "hi", "doc": { "a": true } };
+    let values: Vec<_> = doc.iter_raw_values().collect::<Result<_>>().unwrap();
+    assert_eq!(
+        values,
+        vec![
+            ("int", ElementType::Int32, &5i32.to_le_bytes()[..]),
+            ("str", ElementType::String, &[3, 0, 0, 0, b'h', b'i', 0][..]),
+            (
+                "doc",
+                ElementType::EmbeddedDocument,
+                rawdoc! { "a": true }.as_bytes()
+            ),
+        ]
+    );
+    assert_eq!(rawdoc! {}.iter_raw_values().count(), 0);
+
+    let mut bytes = rawdoc! { "a": "b" }.into_bytes();
+    bytes[7] = 100;
+    let doc = RawDocument::decode_from_bytes(&bytes).unwrap();
+    assert!(doc.iter_raw_values().next().unwrap().is_err());
+}
+
+#[test]
+fn get_raw_bytes_at() {
+    let mut array = RawArrayBuf::new();
+    array.push(5);
+    array.push(rawdoc! { "a": true });
+    array.push("hi");
+
+    assert_eq!(
+        array.get_raw_bytes_at(0).unwrap(),
+        Some((ElementType::Int32, &5i32.to_le_bytes()[..]))
+    );
+    assert_eq!(
+        array.get_raw_bytes_at(1).unwrap(),
+        Some((
+            ElementType::EmbeddedDocument,
+            rawdoc! { "a": true }.as_bytes()
+        ))
+    );
+    assert_eq!(
+        array.get_raw_bytes_at(2).unwrap(),
+        Some((ElementType::String, &[3, 0, 0, 0, b'h', b'i', 0][..]))
+    );
+    assert_eq!(array.get_raw_bytes_at(3).unwrap(), None);
+    assert_eq!(RawArrayBuf::new().get_raw_bytes_at(0).unwrap(), None);
+}
+
+#[test]
+fn into_document() {
+    let doc = doc! {
+        "double": 2.5,
+        "string": "hello",
+        "document": { "a": 1 },
+        "array": [1, "two", { "three": 3 }],
+        "binary": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+        "binary_old": Binary { subtype: BinarySubtype::BinaryOld, bytes: vec![4, 5] },
+        "undefined": Bson::Undefined,
+        "object_id": ObjectId::new(),
+        "boolean": true,
+        "datetime": DateTime::now(),
+        "null": null,
+        "regex": Regex::new("a+", "i").unwrap(),
+        "db_pointer": Bson::DbPointer(crate::DbPointer {
+            namespace: "db.coll".to_string(),
+            id: ObjectId::new(),
+        }),
+        "code": Bson::JavaScriptCode("x".to_string()),
+        "symbol": Bson::Symbol("sym".to_string()),
+        "code_with_scope": Bson::JavaScriptCodeWithScope(crate::JavaScriptCodeWithScope {
+            code: "y".to_string(),
+            scope: doc! { "z": 1 },
+        }),
+        "int32": 5,
+        "timestamp": Timestamp { time: 1, increment: 2 },
+        "int64": 6i64,
+        "decimal128": Decimal128::from_bytes([7; 16]),
+        "max_key": Bson::MaxKey,
+        "min_key": Bson::MinKey,
+    };
+    let raw = RawDocumentBuf::from_document(&doc).unwrap();
+    let roundtrip = raw.into_document().unwrap();
+    assert_eq!(roundtrip, doc);
+    assert!(roundtrip.keys().eq(doc.keys()));
+
+    assert_eq!(
+        RawDocumentBuf::new().into_document().unwrap(),
+        crate::Document::new()
+    );
+
+    let mut bytes = rawdoc! { "a": "b" }.into_bytes();
+    bytes[7] = 100;
+    let raw = RawDocumentBuf::decode_from_bytes(bytes).unwrap();
+    assert!(raw.into_document().is_err());
+}
+
+#[test]
+fn into_raw_array_buf() {
+    let empty = RawDocumentBuf::new().into_raw_array_buf().unwrap();
+    assert!(empty.is_empty());
+
+    let doc = rawdoc! { "0": "a", "1": 2, "2": { "x": true } };
+    let bytes = doc.as_bytes().to_vec();
+    let array = doc.into_raw_array_buf().unwrap();
+    assert_eq!(array.as_bytes(), bytes.as_slice());
+    assert_eq!(array.get_str(0).unwrap(), "a");
+    assert_eq!(array.get_i32(1).unwrap(), 2);
+    assert_eq!(
+        array.get_document(2).unwrap(),
+        rawdoc! { "x": true }.as_ref()
+    );
+    assert_eq!(array.into_iter().count(), 3);
+
+    for doc in [
+        rawdoc! { "1": "a", "0": "b" },
+        rawdoc! { "0": "a", "2": "b" },
+        rawdoc! { "1": "a" },
+        rawdoc! { "0": "a", "x": "b" },
+        rawdoc! { "00": "a" },
+    ] {
+        let error = doc.into_raw_array_buf().unwrap_err();
+        assert!(
+            matches!(error.kind, crate::error::ErrorKind::MalformedBytes { .. }),
+            "{:?}",
+            error
+        );
+    }
+}
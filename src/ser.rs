@@ -32,7 +32,6 @@ use ::serde::{ser::Error as SerdeError, Serialize};
 use crate::{
     bson::{Bson, Document},
     error::{Error, Result},
-    ser::serde::SerializerOptions,
     RawDocumentBuf,
 };
 
@@ -55,8 +54,95 @@ where
     value.serialize(ser)
 }
 
+/// Encode a `T` Serializable into a [`Bson`] value using a [`Serializer`] that presents itself as
+/// human readable.
+///
+/// This is an alias for [`serialize_to_bson`], whose [`Serializer`] is already human readable by
+/// default; it exists to make that intent explicit at call sites. It produces the same result as
+/// wrapping the value in [`HumanReadable`](crate::serde_helpers::HumanReadable) before serializing
+/// it, without requiring the value to be moved or cloned into the wrapper. To serialize a value
+/// as non-human readable, construct a [`Serializer`] with
+/// [`SerializerOptions::human_readable`] set to false.
+pub fn serialize_to_bson_human_readable<T>(value: &T) -> Result<Bson>
+where
+    T: Serialize + ?Sized,
+{
+    serialize_to_bson(value)
+}
+
+/// Options used to configure a [`Serializer`] constructed with [`Serializer::with_options`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SerializerOptions {
+    /// Whether the [`Serializer`] should present itself as human readable.
+    ///
+    /// The default is true.
+    pub human_readable: bool,
+
+    /// The maximum nesting depth of documents and arrays, where the top-level document has a
+    /// depth of 1. Exceeding this depth is an error.
+    ///
+    /// The default is `None`, which places no limit on the depth.
+    pub max_depth: Option<u32>,
+
+    /// The maximum length in bytes of any binary value. Exceeding this length is an error.
+    ///
+    /// The default is `None`, which places no limit on the length.
+    pub deny_oversized_binary: Option<usize>,
+}
+
+impl Default for SerializerOptions {
+    fn default() -> Self {
+        Self {
+            human_readable: true,
+            max_depth: None,
+            deny_oversized_binary: None,
+        }
+    }
+}
+
+impl SerializerOptions {
+    /// Create a builder struct used to construct a [`SerializerOptions`].
+    pub fn builder() -> SerializerOptionsBuilder {
+        SerializerOptionsBuilder {
+            options: Default::default(),
+        }
+    }
+}
+
+/// A builder used to construct a [`SerializerOptions`].
+#[derive(Debug, Clone)]
+pub struct SerializerOptionsBuilder {
+    options: SerializerOptions,
+}
+
+impl SerializerOptionsBuilder {
+    /// Set the value for [`SerializerOptions::human_readable`].
+    pub fn human_readable(mut self, val: impl Into<bool>) -> Self {
+        self.options.human_readable = val.into();
+        self
+    }
+
+    /// Set the value for [`SerializerOptions::max_depth`].
+    pub fn max_depth(mut self, val: impl Into<Option<u32>>) -> Self {
+        self.options.max_depth = val.into();
+        self
+    }
+
+    /// Set the value for [`SerializerOptions::deny_oversized_binary`].
+    pub fn deny_oversized_binary(mut self, val: impl Into<Option<usize>>) -> Self {
+        self.options.deny_oversized_binary = val.into();
+        self
+    }
+
+    /// Consume this builder and produce a [`SerializerOptions`].
+    pub fn build(self) -> SerializerOptions {
+        self.options
+    }
+}
+
 /// Internal-only method to serialize data to BSON with the given options.
-pub(crate) fn to_bson_with_options<T>(value: &T, options: SerializerOptions) -> Result<Bson>
+pub(crate) fn to_bson_with_options<T>(value: &T, options: serde::SerializerOptions) -> Result<Bson>
 where
     T: Serialize + ?Sized,
 {
@@ -77,8 +163,8 @@ where
     match serialize_to_bson(value)? {
         Bson::Document(doc) => Ok(doc),
         bson => Err(Error::serialization(format!(
-            "expected to serialize document, got type {:?} instead",
-            bson.element_type()
+            "expected to serialize document, got type {} instead",
+            bson.type_name()
         ))),
     }
 }
@@ -101,6 +187,40 @@ where
     Ok(serializer.into_vec())
 }
 
+/// Compute the number of bytes that the given `T` occupies when serialized as BSON. This is always
+/// equal to the length of the vector returned by [`serialize_to_vec`], but avoids allocating a
+/// buffer for the whole encoded value.
+///
+/// ```rust
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Cat {
+///     name: String,
+///     age: i32
+/// }
+///
+/// let cat = Cat { name: "Garfield".to_string(), age: 43 };
+/// assert_eq!(bson::bson_size(&cat)?, bson::serialize_to_vec(&cat)?.len());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[inline]
+pub fn bson_size<T>(value: &T) -> Result<usize>
+where
+    T: Serialize,
+{
+    let mut serializer = raw::Serializer::new_count_only();
+    #[cfg(feature = "serde_path_to_error")]
+    {
+        serde_path_to_error::serialize(value, &mut serializer).map_err(Error::with_path)?;
+    }
+    #[cfg(not(feature = "serde_path_to_error"))]
+    {
+        value.serialize(&mut serializer)?;
+    }
+    Ok(serializer.position())
+}
+
 /// Serialize the given `T` as a [`RawDocumentBuf`].
 ///
 /// ```rust
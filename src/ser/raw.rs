@@ -25,6 +25,14 @@ use document_serializer::DocumentSerializer;
 pub(crate) struct Serializer {
     bytes: Vec<u8>,
 
+    /// The number of bytes that have been discarded from the front of `bytes`. This is only ever
+    /// non-zero when `count_only` is set.
+    flushed: usize,
+
+    /// Whether the serialized bytes are being discarded as they are written, in which case only
+    /// the total number of bytes is tracked. Retroactive updates to discarded bytes are skipped.
+    count_only: bool,
+
     /// The index into `bytes` where the current element type will need to be stored.
     /// This needs to be set retroactively because in BSON, the element type comes before the key,
     /// but in serde, the serializer learns of the type after serializing the key.
@@ -62,21 +70,42 @@ impl Serializer {
     pub(crate) fn new() -> Self {
         Self {
             bytes: Vec::new(),
+            flushed: 0,
+            count_only: false,
             type_index: 0,
             hint: SerializerHint::None,
             human_readable: false,
         }
     }
 
+    /// Construct a serializer that only counts the number of bytes that would be serialized,
+    /// holding on to no more than a single element's worth of bytes at any time.
+    pub(crate) fn new_count_only() -> Self {
+        Self {
+            count_only: true,
+            ..Self::new()
+        }
+    }
+
     /// Convert this serializer into the vec of the serialized bytes.
     pub(crate) fn into_vec(self) -> Vec<u8> {
         self.bytes
     }
 
+    /// The total number of bytes serialized so far.
+    #[inline]
+    pub(crate) fn position(&self) -> usize {
+        self.flushed + self.bytes.len()
+    }
+
     /// Reserve a spot for the element type to be set retroactively via `update_element_type`.
     #[inline]
     fn reserve_element_type(&mut self) {
-        self.type_index = self.bytes.len(); // record index
+        if self.count_only {
+            self.flushed += self.bytes.len();
+            self.bytes.clear();
+        }
+        self.type_index = self.position(); // record index
         self.bytes.push(0); // push temporary placeholder
     }
 
@@ -95,15 +124,19 @@ impl Serializer {
             }
         }
 
-        self.bytes[self.type_index] = t as u8;
+        if let Some(index) = self.type_index.checked_sub(self.flushed) {
+            self.bytes[index] = t as u8;
+        }
         Ok(())
     }
 
     /// Replace an i32 value at the given index with the given value.
     #[inline]
     fn replace_i32(&mut self, at: usize, with: i32) {
-        let portion = &mut self.bytes[at..at + 4];
-        portion.copy_from_slice(&with.to_le_bytes());
+        if let Some(at) = at.checked_sub(self.flushed) {
+            let portion = &mut self.bytes[at..at + 4];
+            portion.copy_from_slice(&with.to_le_bytes());
+        }
     }
 
     fn serialize_raw(&mut self, v: RawBsonRef) -> Result<()> {
@@ -440,7 +473,7 @@ pub(crate) struct VariantSerializer<'a> {
 
 impl<'a> VariantSerializer<'a> {
     fn start(rs: &'a mut Serializer, variant: &'static CStr, inner_type: VariantInnerType) -> Self {
-        let doc_start = rs.bytes.len();
+        let doc_start = rs.position();
         // write placeholder length for document, will be updated at end
         static ZERO: RawBsonRef = RawBsonRef::Int32(0);
         ZERO.append_to(&mut rs.bytes);
@@ -451,7 +484,7 @@ impl<'a> VariantSerializer<'a> {
         };
         rs.bytes.push(inner as u8);
         variant.append_to(&mut rs.bytes);
-        let inner_start = rs.bytes.len();
+        let inner_start = rs.position();
         // write placeholder length for inner, will be updated at end
         ZERO.append_to(&mut rs.bytes);
 
@@ -480,13 +513,13 @@ impl<'a> VariantSerializer<'a> {
     fn end_both(self) -> Result<()> {
         // null byte for the inner
         self.root_serializer.bytes.push(0);
-        let arr_length = (self.root_serializer.bytes.len() - self.inner_start) as i32;
+        let arr_length = (self.root_serializer.position() - self.inner_start) as i32;
         self.root_serializer
             .replace_i32(self.inner_start, arr_length);
 
         // null byte for document
         self.root_serializer.bytes.push(0);
-        let doc_length = (self.root_serializer.bytes.len() - self.doc_start) as i32;
+        let doc_length = (self.root_serializer.position() - self.doc_start) as i32;
         self.root_serializer.replace_i32(self.doc_start, doc_length);
         Ok(())
     }
@@ -20,7 +20,7 @@ pub(crate) struct DocumentSerializer<'a> {
 
 impl<'a> DocumentSerializer<'a> {
     pub(crate) fn start(rs: &'a mut Serializer) -> Self {
-        let start = rs.bytes.len();
+        let start = rs.position();
         RawBsonRef::Int32(0).append_to(&mut rs.bytes);
         Self {
             root_serializer: rs,
@@ -57,7 +57,7 @@ impl<'a> DocumentSerializer<'a> {
 
     pub(crate) fn end_doc(self) -> crate::ser::Result<DocumentSerializationResult<'a>> {
         self.root_serializer.bytes.push(0);
-        let length = (self.root_serializer.bytes.len() - self.start) as i32;
+        let length = (self.root_serializer.position() - self.start) as i32;
         self.root_serializer.replace_i32(self.start, length);
         Ok(DocumentSerializationResult {
             root_serializer: self.root_serializer,
@@ -613,7 +613,7 @@ pub(crate) struct CodeWithScopeSerializer<'a> {
 impl<'a> CodeWithScopeSerializer<'a> {
     #[inline]
     fn start(code: &str, rs: &'a mut Serializer) -> Self {
-        let start = rs.bytes.len();
+        let start = rs.position();
         RawBsonRef::Int32(0).append_to(&mut rs.bytes); // placeholder length
         write_string(&mut rs.bytes, code);
 
@@ -646,7 +646,7 @@ impl SerializeMap for CodeWithScopeSerializer<'_> {
     fn end(self) -> Result<Self::Ok> {
         let result = self.doc.end_doc()?;
 
-        let total_len = (result.root_serializer.bytes.len() - self.start) as i32;
+        let total_len = (result.root_serializer.position() - self.start) as i32;
         result.root_serializer.replace_i32(self.start, total_len);
         Ok(())
     }
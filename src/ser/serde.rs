@@ -119,6 +119,74 @@ pub(crate) struct SerializerOptions {
     /// Whether the [`Serializer`] should present itself as human readable or not.
     /// The default value is true. For internal use only.
     pub(crate) human_readable: Option<bool>,
+
+    /// The maximum nesting depth of documents and arrays, where the top-level document has a
+    /// depth of 1.
+    pub(crate) max_depth: Option<u32>,
+
+    /// The maximum length in bytes of binary values.
+    pub(crate) max_binary_len: Option<usize>,
+
+    /// How deeply the value being serialized is nested, where the top-level value has a depth of
+    /// 0.
+    pub(crate) depth: u32,
+}
+
+impl SerializerOptions {
+    /// The options to use for values nested within the current one.
+    fn nested(&self) -> Self {
+        Self {
+            depth: self.depth + 1,
+            ..self.clone()
+        }
+    }
+
+    /// Checks a serialized value against the configured limits if it is the top-level value.
+    ///
+    /// The whole value is checked once it has been serialized rather than as each value is
+    /// produced, since types like [`Binary`] pass through intermediate documents (e.g. `$binary`)
+    /// that do not appear in the output.
+    fn finish(&self, bson: Bson) -> Result<Bson> {
+        if self.depth == 0 && (self.max_depth.is_some() || self.max_binary_len.is_some()) {
+            self.check_limits(&bson, 0)?;
+        }
+        Ok(bson)
+    }
+
+    fn check_limits(&self, bson: &Bson, depth: u32) -> Result<()> {
+        let check_depth = |depth| match self.max_depth {
+            Some(max_depth) if depth > max_depth => Err(Error::serialization(format!(
+                "document exceeds the maximum nesting depth of {}",
+                max_depth
+            ))),
+            _ => Ok(()),
+        };
+        match bson {
+            Bson::Document(doc)
+            | Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope { scope: doc, .. }) => {
+                check_depth(depth + 1)?;
+                doc.values()
+                    .try_for_each(|value| self.check_limits(value, depth + 1))
+            }
+            Bson::Array(array) => {
+                check_depth(depth + 1)?;
+                array
+                    .iter()
+                    .try_for_each(|value| self.check_limits(value, depth + 1))
+            }
+            Bson::Binary(binary) => match self.max_binary_len {
+                Some(max_len) if binary.bytes.len() > max_len => {
+                    Err(Error::serialization(format!(
+                        "binary value of {} bytes exceeds the maximum length of {} bytes",
+                        binary.bytes.len(),
+                        max_len
+                    )))
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Serializer {
@@ -130,6 +198,28 @@ impl Serializer {
         }
     }
 
+    /// Construct a new [`Serializer`] configured with the provided
+    /// [`SerializerOptions`](crate::ser::SerializerOptions).
+    ///
+    /// ```
+    /// use bson::{bson, ser::SerializerOptions, Serializer};
+    /// use serde::Serialize;
+    ///
+    /// let options = SerializerOptions::builder().max_depth(2).build();
+    /// let nested = bson!({ "a": { "b": { "c": 1 } } });
+    /// assert!(nested.serialize(Serializer::with_options(options)).is_err());
+    /// ```
+    pub fn with_options(options: crate::ser::SerializerOptions) -> Serializer {
+        Serializer {
+            options: SerializerOptions {
+                human_readable: Some(options.human_readable),
+                max_depth: options.max_depth,
+                max_binary_len: options.deny_oversized_binary,
+                depth: 0,
+            },
+        }
+    }
+
     /// Construct a new [`Serializer`] configured with the provided [`SerializerOptions`].
     pub(crate) fn new_with_options(options: SerializerOptions) -> Self {
         Serializer { options }
@@ -226,7 +316,7 @@ impl ser::Serializer for Serializer {
         //     state.serialize_element(byte)?;
         // }
         // state.end()
-        Ok(Bson::Binary(Binary {
+        self.options.finish(Bson::Binary(Binary {
             subtype: BinarySubtype::Generic,
             bytes: value.to_vec(),
         }))
@@ -277,49 +367,56 @@ impl ser::Serializer for Serializer {
         match name {
             UUID_NEWTYPE_NAME => {
                 let is_human_readable = self.is_human_readable();
-                match value.serialize(self)? {
+                let options = self.options.clone();
+                let uuid = match value.serialize(self)? {
                     Bson::String(s) if is_human_readable => {
                         // the serializer reports itself as human readable, so [`Uuid`] will
                         // serialize itself as a string.
                         let uuid = crate::Uuid::parse_str(s).map_err(Error::custom)?;
-                        Ok(Bson::Binary(uuid.into()))
+                        Bson::Binary(uuid.into())
                     }
-                    Bson::Binary(b) if !is_human_readable => Ok(Bson::Binary(Binary {
+                    Bson::Binary(b) if !is_human_readable => Bson::Binary(Binary {
                         bytes: b.bytes,
                         subtype: BinarySubtype::Uuid,
-                    })),
+                    }),
                     b => {
                         let expectation = if is_human_readable {
                             "a string"
                         } else {
                             "bytes"
                         };
-                        Err(Error::custom(format!(
+                        return Err(Error::custom(format!(
                             "expected UUID to be serialized as {} but got {:?} instead",
                             expectation, b
-                        )))
+                        )));
                     }
-                }
+                };
+                options.finish(uuid)
             }
             // when in non-human-readable mode, raw document / raw array will serialize as bytes.
-            RAW_DOCUMENT_NEWTYPE | RAW_ARRAY_NEWTYPE if !self.is_human_readable() => match value
-                .serialize(self)?
-            {
-                Bson::Binary(b) => {
-                    let doc =
-                        Document::decode_from_reader(b.bytes.as_slice()).map_err(Error::custom)?;
-
-                    if name == RAW_DOCUMENT_NEWTYPE {
-                        Ok(Bson::Document(doc))
-                    } else {
-                        Ok(Bson::Array(doc.into_iter().map(|kvp| kvp.1).collect()))
+            RAW_DOCUMENT_NEWTYPE | RAW_ARRAY_NEWTYPE if !self.is_human_readable() => {
+                // the bytes are nested so that they are not checked against the binary length
+                // limit themselves
+                let bytes = value.serialize(Serializer::new_with_options(self.options.nested()))?;
+                match bytes {
+                    Bson::Binary(b) => {
+                        let doc = Document::decode_from_reader(b.bytes.as_slice())
+                            .map_err(Error::custom)?;
+
+                        if name == RAW_DOCUMENT_NEWTYPE {
+                            self.options.finish(Bson::Document(doc))
+                        } else {
+                            self.options
+                                .finish(Bson::Array(doc.into_iter().map(|kvp| kvp.1).collect()))
+                        }
                     }
+                    b => Err(Error::custom(format!(
+                        "expected raw document or array to be serialized as bytes but got {:?} \
+                         instead",
+                        b
+                    ))),
                 }
-                b => Err(Error::custom(format!(
-                    "expected raw document or array to be serialized as bytes but got {:?} instead",
-                    b
-                ))),
-            },
+            }
             HUMAN_READABLE_NEWTYPE => {
                 self.options.human_readable = Some(true);
                 value.serialize(self)
@@ -340,8 +437,8 @@ impl ser::Serializer for Serializer {
         T: Serialize + ?Sized,
     {
         let mut newtype_variant = Document::new();
-        newtype_variant.insert(variant, to_bson_with_options(value, self.options)?);
-        Ok(newtype_variant.into())
+        newtype_variant.insert(variant, to_bson_with_options(value, self.options.nested())?);
+        self.options.finish(newtype_variant.into())
     }
 
     #[inline]
@@ -440,12 +537,12 @@ impl SerializeSeq for ArraySerializer {
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> crate::ser::Result<()> {
         self.inner
-            .push(to_bson_with_options(value, self.options.clone())?);
+            .push(to_bson_with_options(value, self.options.nested())?);
         Ok(())
     }
 
     fn end(self) -> crate::ser::Result<Bson> {
-        Ok(Bson::Array(self.inner))
+        self.options.finish(Bson::Array(self.inner))
     }
 }
 
@@ -461,12 +558,12 @@ impl SerializeTuple for TupleSerializer {
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> crate::ser::Result<()> {
         self.inner
-            .push(to_bson_with_options(value, self.options.clone())?);
+            .push(to_bson_with_options(value, self.options.nested())?);
         Ok(())
     }
 
     fn end(self) -> crate::ser::Result<Bson> {
-        Ok(Bson::Array(self.inner))
+        self.options.finish(Bson::Array(self.inner))
     }
 }
 
@@ -482,12 +579,12 @@ impl SerializeTupleStruct for TupleStructSerializer {
 
     fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> crate::ser::Result<()> {
         self.inner
-            .push(to_bson_with_options(value, self.options.clone())?);
+            .push(to_bson_with_options(value, self.options.nested())?);
         Ok(())
     }
 
     fn end(self) -> crate::ser::Result<Bson> {
-        Ok(Bson::Array(self.inner))
+        self.options.finish(Bson::Array(self.inner))
     }
 }
 
@@ -504,14 +601,14 @@ impl SerializeTupleVariant for TupleVariantSerializer {
 
     fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> crate::ser::Result<()> {
         self.inner
-            .push(to_bson_with_options(value, self.options.clone())?);
+            .push(to_bson_with_options(value, self.options.nested())?);
         Ok(())
     }
 
     fn end(self) -> crate::ser::Result<Bson> {
         let mut tuple_variant = Document::new();
         tuple_variant.insert(self.name, self.inner);
-        Ok(tuple_variant.into())
+        self.options.finish(tuple_variant.into())
     }
 }
 
@@ -527,7 +624,7 @@ impl SerializeMap for MapSerializer {
     type Error = Error;
 
     fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
-        self.next_key = match to_bson_with_options(&key, self.options.clone())? {
+        self.next_key = match to_bson_with_options(&key, self.options.nested())? {
             Bson::String(s) => Some(s),
             other => return Err(Error::invalid_key_type(other.element_type().name())),
         };
@@ -537,12 +634,13 @@ impl SerializeMap for MapSerializer {
     fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> crate::ser::Result<()> {
         let key = self.next_key.take().unwrap_or_default();
         self.inner
-            .insert(key, to_bson_with_options(&value, self.options.clone())?);
+            .insert(key, to_bson_with_options(&value, self.options.nested())?);
         Ok(())
     }
 
     fn end(self) -> crate::ser::Result<Bson> {
-        Ok(Bson::from_extended_document(self.inner))
+        self.options
+            .finish(Bson::from_extended_document(self.inner))
     }
 }
 
@@ -562,12 +660,13 @@ impl SerializeStruct for StructSerializer {
         value: &T,
     ) -> crate::ser::Result<()> {
         self.inner
-            .insert(key, to_bson_with_options(value, self.options.clone())?);
+            .insert(key, to_bson_with_options(value, self.options.nested())?);
         Ok(())
     }
 
     fn end(self) -> crate::ser::Result<Bson> {
-        Ok(Bson::from_extended_document(self.inner))
+        self.options
+            .finish(Bson::from_extended_document(self.inner))
     }
 }
 
@@ -588,7 +687,7 @@ impl SerializeStructVariant for StructVariantSerializer {
         value: &T,
     ) -> crate::ser::Result<()> {
         self.inner
-            .insert(key, to_bson_with_options(value, self.options.clone())?);
+            .insert(key, to_bson_with_options(value, self.options.nested())?);
         Ok(())
     }
 
@@ -598,7 +697,7 @@ impl SerializeStructVariant for StructVariantSerializer {
         let mut struct_variant = Document::new();
         struct_variant.insert(self.name, var);
 
-        Ok(Bson::Document(struct_variant))
+        self.options.finish(Bson::Document(struct_variant))
     }
 }
 
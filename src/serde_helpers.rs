@@ -7,6 +7,13 @@ use std::{
     result::Result,
 };
 
+#[doc(inline)]
+pub use f32_as_f64::{deserialize as deserialize_f32_from_f64, serialize as serialize_f32_as_f64};
+#[doc(inline)]
+pub use i64_as_datetime_millis::{
+    deserialize as deserialize_i64_from_datetime_millis,
+    serialize as serialize_i64_as_datetime_millis,
+};
 #[doc(inline)]
 pub use timestamp_as_u32::{
     deserialize as deserialize_timestamp_from_u32,
@@ -76,13 +83,17 @@ pub fn serialize_u64_as_i64<S: Serializer>(val: &u64, serializer: S) -> Result<S
     }
 }
 
-#[cfg(feature = "serde_with-3")]
 pub mod object_id {
+    #[cfg(feature = "serde_with-3")]
     use crate::{macros::serde_conv_doc, oid::ObjectId};
+    #[cfg(feature = "serde_with-3")]
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    #[cfg(feature = "serde_with-3")]
     use serde_with::{DeserializeAs, SerializeAs};
 
+    #[cfg(feature = "serde_with-3")]
     serde_conv_doc!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
         /// Contains functions to serialize an ObjectId as a hex string and deserialize an
         /// ObjectId from a hex string
         /// ```rust
@@ -110,7 +121,9 @@ pub mod object_id {
         }
     );
 
+    #[cfg(feature = "serde_with-3")]
     serde_conv_doc!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
         /// Contains functions to serialize a hex string as an ObjectId and deserialize a
         /// hex string from an ObjectId
         /// ```rust
@@ -136,6 +149,136 @@ pub mod object_id {
             Ok(oid.to_hex())
         }
     );
+
+    #[cfg(feature = "serde_with-3")]
+    serde_conv_doc!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
+        /// Contains functions to serialize an ObjectId as a [`Uuid`](crate::Uuid) and deserialize
+        /// an ObjectId from a [`Uuid`](crate::Uuid), for interoperating with systems that store
+        /// ObjectIds in UUID fields.
+        ///
+        /// The UUID consists of four zero bytes followed by the 12 bytes of the ObjectId.
+        /// Deserializing a UUID whose first four bytes are not all zero returns an error.
+        /// ```rust
+        /// # #[cfg(feature = "serde_with-3")]
+        /// {
+        /// # use serde::{Serialize, Deserialize};
+        /// # use bson::serde_helpers::object_id;
+        /// # use serde_with::serde_as;
+        /// # use bson::oid::ObjectId;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct Item {
+        ///     #[serde_as(as = "object_id::AsUuid")]
+        ///     pub id: ObjectId,
+        /// }
+        /// # }
+        /// ```
+        pub AsUuid,
+        ObjectId,
+        |oid: &ObjectId| -> Result<crate::Uuid, String> {
+            let mut bytes = [0; 16];
+            bytes[4..].copy_from_slice(&oid.bytes());
+            Ok(crate::Uuid::from_bytes(bytes))
+        },
+        |uuid: crate::Uuid| -> Result<ObjectId, String> {
+            let bytes = uuid.bytes();
+            if bytes[..4] != [0; 4] {
+                return Err(format!(
+                    "cannot convert UUID {} to ObjectId: the first four bytes must be zero",
+                    uuid
+                ));
+            }
+            let mut oid = [0; 12];
+            oid.copy_from_slice(&bytes[4..]);
+            Ok(ObjectId::from_bytes(oid))
+        }
+    );
+
+    /// Contains functions to serialize a newtype wrapper around an ObjectId as a plain ObjectId
+    /// and deserialize the wrapper from a plain ObjectId. The wrapper type does not need to
+    /// implement `Serialize` or `Deserialize` itself.
+    ///
+    /// ```rust
+    /// # use serde::{Serialize, Deserialize};
+    /// # use bson::{oid::ObjectId, serde_helpers::object_id};
+    /// #[derive(Clone)]
+    /// struct UserId(ObjectId);
+    ///
+    /// impl From<ObjectId> for UserId {
+    ///     fn from(oid: ObjectId) -> Self {
+    ///         Self(oid)
+    ///     }
+    /// }
+    ///
+    /// impl From<UserId> for ObjectId {
+    ///     fn from(id: UserId) -> Self {
+    ///         id.0
+    ///     }
+    /// }
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct User {
+    ///     #[serde(with = "object_id::as_inner")]
+    ///     pub id: UserId,
+    /// }
+    /// ```
+    pub mod as_inner {
+        use crate::oid::ObjectId;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+        /// Serializes a newtype wrapper as the ObjectId it contains.
+        pub fn serialize<T, S>(val: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Clone + Into<ObjectId>,
+            S: Serializer,
+        {
+            let oid: ObjectId = val.clone().into();
+            oid.serialize(serializer)
+        }
+
+        /// Deserializes a newtype wrapper from an ObjectId.
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: From<ObjectId>,
+            D: Deserializer<'de>,
+        {
+            ObjectId::deserialize(deserializer).map(T::from)
+        }
+    }
+}
+
+/// Contains functions to serialize an i64 as a [`crate::DateTime`] and deserialize an i64 from a
+/// [`crate::DateTime`]. The i64 should represent milliseconds since the Unix epoch.
+///
+/// This is equivalent to `datetime::FromI64`, but does not require the `serde_with-3` feature.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::i64_as_datetime_millis;
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "i64_as_datetime_millis")]
+///     pub created_at: i64,
+/// }
+/// ```
+pub mod i64_as_datetime_millis {
+    use crate::DateTime;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Serializes an i64 as a [`DateTime`].
+    pub fn serialize<S: Serializer>(val: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        DateTime::from_millis(*val).serialize(serializer)
+    }
+
+    /// Deserializes an i64 from a [`DateTime`].
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<i64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DateTime::deserialize(deserializer).map(|date| date.timestamp_millis())
+    }
 }
 
 /// Contains functions to serialize a u32 as an f64 (BSON double) and deserialize a
@@ -219,6 +362,149 @@ pub mod u64_as_f64 {
     }
 }
 
+/// Contains functions to serialize an i32 as an f64 (BSON double) and deserialize an i32 from an
+/// f64 (BSON double), for data written by producers that store every number as a double.
+///
+/// Deserialization errors if the value has a fractional part or is outside the range of i32.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::i32_as_f64;
+/// #[derive(Serialize, Deserialize)]
+/// struct Product {
+///     #[serde(with = "i32_as_f64")]
+///     pub quantity: i32,
+/// }
+/// ```
+pub mod i32_as_f64 {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Deserializes an i32 from an f64 (BSON double). Errors if an exact conversion is not
+    /// possible.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<i32, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let f = f64::deserialize(deserializer)?;
+        if f.fract() == 0.0 && f >= f64::from(i32::MIN) && f <= f64::from(i32::MAX) {
+            Ok(f as i32)
+        } else {
+            Err(de::Error::custom(format!(
+                "cannot convert f64 (BSON double) {} to i32",
+                f
+            )))
+        }
+    }
+
+    /// Serializes an i32 as an f64 (BSON double).
+    pub fn serialize<S: Serializer>(val: &i32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(f64::from(*val))
+    }
+}
+
+/// Contains functions to serialize an i64 as an f64 (BSON double) and deserialize an i64 from an
+/// f64 (BSON double), for data written by producers that store every number as a double.
+///
+/// An f64 can only represent every integer up to 2^53 in magnitude exactly, so serialization errors
+/// for larger values that would lose precision. Deserialization errors if the value has a
+/// fractional part or is outside the range of i64.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::i64_as_f64;
+/// #[derive(Serialize, Deserialize)]
+/// struct Account {
+///     #[serde(with = "i64_as_f64")]
+///     pub balance_cents: i64,
+/// }
+/// ```
+pub mod i64_as_f64 {
+    use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    /// Deserializes an i64 from an f64 (BSON double). Errors if an exact conversion is not
+    /// possible.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<i64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let f = f64::deserialize(deserializer)?;
+        // i64::MAX is not representable as an f64 and rounds up to 2^63, which is out of range.
+        if f.fract() == 0.0 && f >= i64::MIN as f64 && f < i64::MAX as f64 {
+            Ok(f as i64)
+        } else {
+            Err(de::Error::custom(format!(
+                "cannot convert f64 (BSON double) {} to i64",
+                f
+            )))
+        }
+    }
+
+    /// Serializes an i64 as an f64 (BSON double). Errors if an exact conversion is not possible.
+    pub fn serialize<S: Serializer>(val: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        let f = *val as f64;
+        if f < i64::MAX as f64 && f as i64 == *val {
+            serializer.serialize_f64(f)
+        } else {
+            Err(ser::Error::custom(format!(
+                "cannot convert i64 {} to f64 (BSON double)",
+                val
+            )))
+        }
+    }
+}
+
+/// Contains functions to serialize an f32 as an f64 (BSON double) and deserialize an f32 from an
+/// f64 (BSON double).
+///
+/// Deserialization errors if the value cannot be represented exactly as an f32, either because it
+/// is finite but outside the range of f32 or because it has more precision than an f32 can hold
+/// (e.g. `0.1f64`). Since every f32 widens to an f64 exactly, values written by this module always
+/// deserialize successfully. Infinities and NaN are preserved.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::f32_as_f64;
+/// #[derive(Serialize, Deserialize)]
+/// struct Reading {
+///     #[serde(with = "f32_as_f64")]
+///     pub temperature: f32,
+/// }
+/// ```
+pub mod f32_as_f64 {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    /// Deserializes an f32 from an f64 (BSON double). Errors if the value cannot be represented
+    /// exactly as an f32.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f32, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let f = f64::deserialize(deserializer)?;
+        f64_to_f32(f).map_err(de::Error::custom)
+    }
+
+    /// Serializes an f32 as an f64 (BSON double).
+    pub fn serialize<S: Serializer>(val: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(f64::from(*val))
+    }
+
+    pub(crate) fn f64_to_f32(f: f64) -> Result<f32, String> {
+        if f.is_finite() && f.abs() > f64::from(f32::MAX) {
+            Err(format!(
+                "cannot convert f64 (BSON double) {} to f32: out of range",
+                f
+            ))
+        } else if !f.is_nan() && f64::from(f as f32) != f {
+            Err(format!(
+                "cannot convert f64 (BSON double) {} to f32 without losing precision",
+                f
+            ))
+        } else {
+            Ok(f as f32)
+        }
+    }
+}
+
 /// Type converters for serializing and deserializing [`crate::DateTime`] using
 /// [`serde_with::serde_as`].
 ///
@@ -227,10 +513,17 @@ pub mod u64_as_f64 {
 /// - [`datetime::FromRfc3339String`] — converts a RFC 3339 string to and from a
 ///   [`crate::DateTime`].
 /// - [`datetime::FromI64`] — converts an `i64` to and from a [`crate::DateTime`].
+/// - [`datetime::FromU32Seconds`] — converts a `u32` number of seconds to and from a
+///   [`crate::DateTime`].
 /// - [`datetime::FromChrono04DateTime`] — converts a [`chrono::DateTime`] to and from a
 ///   [`crate::DateTime`].
 /// - [`datetime::FromTime03OffsetDateTime`] — converts a [`time::OffsetDateTime`] to and from a
 ///   [`crate::DateTime`].
+/// - [`datetime::AsHttpDate`] — converts a [`crate::DateTime`] to and from an HTTP date string.
+/// - [`datetime::AsTimezoneAware`] — converts a [`chrono::DateTime<chrono::FixedOffset>`] to and
+///   from a document holding a [`crate::DateTime`] and the original UTC offset.
+/// - [`datetime::FromStringWithFormat`] — converts a string with a custom format to and from a
+///   [`crate::DateTime`].
 #[cfg(feature = "serde_with-3")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
 pub mod datetime {
@@ -323,6 +616,40 @@ pub mod datetime {
         }
     );
 
+    serde_conv_doc!(
+        /// Converts a `u32` to and from a [`DateTime`].
+        ///
+        /// The `u32` should represent seconds since the Unix epoch, as used by some legacy
+        /// systems. When deserializing, the [`DateTime`] is rounded to the nearest second, and an
+        /// error is returned if the result does not fit in a `u32`.
+        /// ```rust
+        /// # #[cfg(feature = "serde_with-3")]
+        /// # {
+        /// use bson::serde_helpers::datetime;
+        /// use serde::{Serialize, Deserialize};
+        /// use serde_with::serde_as;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct Session {
+        ///     #[serde_as(as = "datetime::FromU32Seconds")]
+        ///     pub expires_at: u32,
+        /// }
+        /// # }
+        /// ```
+        pub FromU32Seconds,
+        u32,
+        |value: &u32| -> Result<DateTime, String> {
+            Ok(DateTime::from_millis(i64::from(*value) * 1000))
+        },
+        |date: DateTime| -> Result<u32, String> {
+            let millis = i128::from(date.timestamp_millis());
+            let seconds = (millis + 500).div_euclid(1000);
+            u32::try_from(seconds).map_err(|_| {
+                format!("Cannot convert DateTime {} to u32 seconds: out of range", date)
+            })
+        }
+    );
+
     #[cfg(feature = "chrono-0_4")]
     serde_conv_doc!(
         #[cfg_attr(docsrs, doc(cfg(feature = "chrono-0_4")))]
@@ -351,6 +678,220 @@ pub mod datetime {
         }
     );
 
+    #[cfg(feature = "chrono-0_4")]
+    const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+    #[cfg(feature = "chrono-0_4")]
+    serde_conv_doc!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "chrono-0_4")))]
+        /// Converts a [`DateTime`] to and from an HTTP date string, i.e. the IMF-fixdate format
+        /// defined in RFC 7231 (e.g. `"Mon, 07 Aug 2023 12:00:00 GMT"`).
+        ///
+        /// HTTP dates have second precision, so any milliseconds are dropped when serializing.
+        /// ```rust
+        /// # #[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
+        /// # {
+        /// use bson::{serde_helpers::datetime, DateTime};
+        /// use serde::{Serialize, Deserialize};
+        /// use serde_with::serde_as;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct RequestLog {
+        ///     #[serde_as(as = "datetime::AsHttpDate")]
+        ///     pub date: DateTime,
+        /// }
+        /// # }
+        /// ```
+        pub AsHttpDate,
+        DateTime,
+        |date: &DateTime| -> Result<String, String> {
+            Ok(date.to_chrono().format(HTTP_DATE_FORMAT).to_string())
+        },
+        |string: String| -> Result<DateTime, String> {
+            chrono::NaiveDateTime::parse_from_str(&string, HTTP_DATE_FORMAT)
+                .map(|naive| {
+                    DateTime::from_chrono(chrono::TimeZone::from_utc_datetime(&Utc, &naive))
+                })
+                .map_err(|e| format!("Cannot parse HTTP date string {} as DateTime: {}", string, e))
+        }
+    );
+
+    #[cfg(feature = "chrono-0_4")]
+    serde_conv_doc!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "chrono-0_4")))]
+        /// Converts a [`chrono::DateTime<chrono::FixedOffset>`] to and from a document of the form
+        /// `{ "utc": <DateTime>, "tz": "+05:30" }`, preserving the original UTC offset alongside
+        /// the BSON [`DateTime`].
+        ///
+        /// The offset is stored as `"+HH:MM"` or `"-HH:MM"`, with a `":SS"` suffix for offsets
+        /// that include seconds (e.g. historical local mean time); any other format is rejected
+        /// when deserializing. As with [`DateTime`], sub-millisecond precision is dropped.
+        /// ```rust
+        /// # #[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
+        /// # {
+        /// use bson::serde_helpers::datetime;
+        /// use serde::{Serialize, Deserialize};
+        /// use serde_with::serde_as;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct Meeting {
+        ///     #[serde_as(as = "datetime::AsTimezoneAware")]
+        ///     pub start: chrono::DateTime<chrono::FixedOffset>,
+        /// }
+        /// # }
+        /// ```
+        pub AsTimezoneAware,
+        chrono::DateTime<chrono::FixedOffset>,
+        |date: &chrono::DateTime<chrono::FixedOffset>| -> Result<crate::Document, String> {
+            Ok(crate::doc! {
+                "utc": DateTime::from_chrono(*date),
+                "tz": date.offset().to_string(),
+            })
+        },
+        |doc: crate::Document| -> Result<chrono::DateTime<chrono::FixedOffset>, String> {
+            let utc = doc
+                .get_datetime("utc")
+                .map_err(|e| format!("Cannot read timezone-aware DateTime: {}", e))?;
+            let tz = doc
+                .get_str("tz")
+                .map_err(|e| format!("Cannot read timezone-aware DateTime: {}", e))?;
+            let offset = parse_utc_offset(tz)
+                .ok_or_else(|| format!("Cannot parse timezone {} as a UTC offset", tz))?;
+            Ok(utc.to_chrono().with_timezone(&offset))
+        }
+    );
+
+    /// Parses a UTC offset of the form `"+HH:MM"` or `"-HH:MM"`, optionally followed by `":SS"`.
+    #[cfg(feature = "chrono-0_4")]
+    fn parse_utc_offset(tz: &str) -> Option<chrono::FixedOffset> {
+        let (sign, rest) = match tz.as_bytes().first()? {
+            b'+' => (1, &tz[1..]),
+            b'-' => (-1, &tz[1..]),
+            _ => return None,
+        };
+        let digits = |s: &str| -> Option<i32> {
+            if s.len() == 2 && s.bytes().all(|b| b.is_ascii_digit()) {
+                s.parse().ok()
+            } else {
+                None
+            }
+        };
+        let mut parts = rest.split(':');
+        let hours = digits(parts.next()?)?;
+        let minutes = digits(parts.next()?)?;
+        let seconds = parts.next().map(digits).unwrap_or(Some(0))?;
+        if parts.next().is_some() || minutes >= 60 || seconds >= 60 {
+            return None;
+        }
+        chrono::FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60 + seconds))
+    }
+
+    /// A format string for use with [`FromStringWithFormat`], in the syntax of
+    /// [`chrono::format::strftime`].
+    #[cfg(feature = "chrono-0_4")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono-0_4")))]
+    pub trait DateTimeFormat {
+        /// The format string.
+        const FORMAT: &'static str;
+    }
+
+    /// Converts a string in the format given by `F` to and from a [`DateTime`].
+    ///
+    /// Strings without an offset are interpreted as UTC, and strings without a time as midnight.
+    /// An error is returned if the format string is invalid or a string does not match it.
+    /// ```rust
+    /// # #[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
+    /// # {
+    /// use bson::serde_helpers::datetime::{self, DateTimeFormat};
+    /// use serde::{Serialize, Deserialize};
+    /// use serde_with::serde_as;
+    ///
+    /// struct SlashDate;
+    ///
+    /// impl DateTimeFormat for SlashDate {
+    ///     const FORMAT: &'static str = "%Y/%m/%d";
+    /// }
+    ///
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Event {
+    ///     #[serde_as(as = "datetime::FromStringWithFormat<SlashDate>")]
+    ///     pub date: String,
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono-0_4")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono-0_4")))]
+    pub struct FromStringWithFormat<F: DateTimeFormat>(std::marker::PhantomData<F>);
+
+    #[cfg(feature = "chrono-0_4")]
+    impl<F: DateTimeFormat> FromStringWithFormat<F> {
+        /// Serializes a string in the format given by `F` as a [`DateTime`].
+        pub fn serialize<S>(string: &str, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let parsed = chrono::DateTime::parse_from_str(string, F::FORMAT)
+                .map(|date| date.with_timezone(&Utc))
+                .or_else(|_| {
+                    chrono::NaiveDateTime::parse_from_str(string, F::FORMAT)
+                        .or_else(|_| {
+                            chrono::NaiveDate::parse_from_str(string, F::FORMAT)
+                                .map(|naive| naive.and_time(chrono::NaiveTime::default()))
+                        })
+                        .map(|naive| chrono::TimeZone::from_utc_datetime(&Utc, &naive))
+                })
+                .map_err(|e| {
+                    serde::ser::Error::custom(format!(
+                        "Cannot parse string {} with format {} as DateTime: {}",
+                        string,
+                        F::FORMAT,
+                        e
+                    ))
+                })?;
+            DateTime::from_chrono(parsed).serialize(serializer)
+        }
+
+        /// Deserializes a string in the format given by `F` from a [`DateTime`].
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            use std::fmt::Write;
+
+            let date = DateTime::deserialize(deserializer)?;
+            let mut string = String::new();
+            write!(string, "{}", date.to_chrono().format(F::FORMAT)).map_err(|_| {
+                serde::de::Error::custom(format!(
+                    "Cannot format DateTime {} with format {}: invalid format string",
+                    date,
+                    F::FORMAT
+                ))
+            })?;
+            Ok(string)
+        }
+    }
+
+    #[cfg(feature = "chrono-0_4")]
+    impl<F: DateTimeFormat> SerializeAs<String> for FromStringWithFormat<F> {
+        fn serialize_as<S>(string: &String, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            Self::serialize(string, serializer)
+        }
+    }
+
+    #[cfg(feature = "chrono-0_4")]
+    impl<'de, F: DateTimeFormat> DeserializeAs<'de, String> for FromStringWithFormat<F> {
+        fn deserialize_as<D>(deserializer: D) -> Result<String, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            Self::deserialize(deserializer)
+        }
+    }
+
     #[cfg(feature = "time-0_3")]
     serde_conv_doc!(
         #[cfg_attr(docsrs, doc(cfg(feature = "time-0_3")))]
@@ -405,30 +946,168 @@ macro_rules! as_binary_mod {
     };
 }
 
-/// Contains functions to serialize a [`uuid::Uuid`] as a [`crate::Binary`] and deserialize a
-/// [`uuid::Uuid`] from a [`crate::Binary`].
-///
-/// ```rust
-/// # #[cfg(feature = "uuid-1")]
-/// # {
-/// use serde::{Serialize, Deserialize};
-/// use uuid::Uuid;
-/// use bson::serde_helpers::uuid_1_as_binary;
+/// Type converters for serializing and deserializing [`crate::Document`] using
+/// [`serde_with::serde_as`].
 ///
-/// #[derive(Serialize, Deserialize)]
-/// struct Item {
-///     #[serde(with = "uuid_1_as_binary")]
-///     pub id: Uuid,
-/// }
-/// # }
-/// ```
-#[cfg(feature = "uuid-1")]
-#[cfg_attr(docsrs, doc(cfg(feature = "uuid-1")))]
-pub mod uuid_1_as_binary {
-    as_binary_mod!(cfg(feature = "uuid-1"), uuid::Uuid);
-}
-
-#[allow(unused_macros)]
+/// ## Available converters
+/// - [`document::AsVecPairs`] — converts a [`crate::Document`] to and from an array of `[key,
+///   value]` pairs.
+#[cfg(feature = "serde_with-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
+pub mod document {
+    use crate::{Bson, Document};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+    use std::result::Result;
+
+    serde_conv_doc!(
+        /// Converts a [`Document`] to and from an array of two-element `[key, value]` arrays,
+        /// preserving the order of the keys.
+        ///
+        /// If the same key appears in more than one pair when deserializing, the last value wins.
+        /// ```rust
+        /// # #[cfg(feature = "serde_with-3")]
+        /// # {
+        /// use bson::{serde_helpers::document, Document};
+        /// use serde::{Serialize, Deserialize};
+        /// use serde_with::serde_as;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct Item {
+        ///     #[serde_as(as = "document::AsVecPairs")]
+        ///     pub fields: Document,
+        /// }
+        /// # }
+        /// ```
+        pub AsVecPairs,
+        Document,
+        |doc: &Document| -> Result<Vec<(String, Bson)>, String> {
+            Ok(doc.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        },
+        |pairs: Vec<(String, Bson)>| -> Result<Document, String> {
+            Ok(pairs.into_iter().collect())
+        }
+    );
+}
+
+/// Type converters for serializing and deserializing `f32` values as BSON doubles using
+/// [`serde_with::serde_as`].
+///
+/// ## Available converters
+/// - [`small_float::AsF64`] — converts an `f32` to and from an `f64` (BSON double).
+#[cfg(feature = "serde_with-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
+pub mod small_float {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+    use std::result::Result;
+
+    serde_conv_doc!(
+        /// Converts an `f32` to and from an `f64` (BSON double). See [`super::f32_as_f64`] for
+        /// details of how values that are out of range or would lose precision are handled.
+        /// ```rust
+        /// # #[cfg(feature = "serde_with-3")]
+        /// # {
+        /// use bson::serde_helpers::small_float;
+        /// use serde::{Serialize, Deserialize};
+        /// use serde_with::serde_as;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct Reading {
+        ///     #[serde_as(as = "Option<small_float::AsF64>")]
+        ///     pub temperature: Option<f32>,
+        /// }
+        /// # }
+        /// ```
+        pub AsF64,
+        f32,
+        |value: &f32| -> Result<f64, String> {
+            Ok(f64::from(*value))
+        },
+        |value: f64| -> Result<f32, String> {
+            super::f32_as_f64::f64_to_f32(value)
+        }
+    );
+}
+
+/// Type converters for serializing and deserializing [`crate::Timestamp`] using
+/// [`serde_with::serde_as`].
+///
+/// ## Available converters
+/// - [`timestamp::AsHumanReadable`] — converts a [`crate::Timestamp`] to and from a document with
+///   `time` and `increment` fields.
+#[cfg(feature = "serde_with-3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
+pub mod timestamp {
+    use crate::Timestamp;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_with::{DeserializeAs, SerializeAs};
+    use std::result::Result;
+
+    #[derive(Serialize, Deserialize)]
+    struct TimestampFields {
+        time: u32,
+        increment: u32,
+    }
+
+    serde_conv_doc!(
+        /// Converts a [`Timestamp`] to and from a plain document of the form
+        /// `{ "time": <u32>, "increment": <u32> }` rather than a BSON timestamp.
+        /// ```rust
+        /// # #[cfg(feature = "serde_with-3")]
+        /// # {
+        /// use bson::{serde_helpers::timestamp, Timestamp};
+        /// use serde::{Serialize, Deserialize};
+        /// use serde_with::serde_as;
+        /// #[serde_as]
+        /// #[derive(Serialize, Deserialize)]
+        /// struct Event {
+        ///     #[serde_as(as = "timestamp::AsHumanReadable")]
+        ///     pub at: Timestamp,
+        /// }
+        /// # }
+        /// ```
+        pub AsHumanReadable,
+        Timestamp,
+        |ts: &Timestamp| -> Result<TimestampFields, String> {
+            Ok(TimestampFields {
+                time: ts.time,
+                increment: ts.increment,
+            })
+        },
+        |fields: TimestampFields| -> Result<Timestamp, String> {
+            Ok(Timestamp {
+                time: fields.time,
+                increment: fields.increment,
+            })
+        }
+    );
+}
+
+/// Contains functions to serialize a [`uuid::Uuid`] as a [`crate::Binary`] and deserialize a
+/// [`uuid::Uuid`] from a [`crate::Binary`].
+///
+/// ```rust
+/// # #[cfg(feature = "uuid-1")]
+/// # {
+/// use serde::{Serialize, Deserialize};
+/// use uuid::Uuid;
+/// use bson::serde_helpers::uuid_1_as_binary;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "uuid_1_as_binary")]
+///     pub id: Uuid,
+/// }
+/// # }
+/// ```
+#[cfg(feature = "uuid-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid-1")))]
+pub mod uuid_1_as_binary {
+    as_binary_mod!(cfg(feature = "uuid-1"), uuid::Uuid);
+}
+
+#[allow(unused_macros)]
 macro_rules! as_legacy_binary_mod {
     ($feat:meta, $uu:path, $rep:path) => {
         use crate::{uuid::UuidRepresentation, Binary};
@@ -498,122 +1177,827 @@ pub mod uuid_1_as_java_legacy_binary {
 /// use bson::serde_helpers::uuid_1_as_python_legacy_binary;
 ///
 /// #[derive(Serialize, Deserialize)]
-/// struct Item {
-///     #[serde(with = "uuid_1_as_python_legacy_binary")]
-///     pub id: Uuid,
+/// struct Item {
+///     #[serde(with = "uuid_1_as_python_legacy_binary")]
+///     pub id: Uuid,
+/// }
+/// # }
+/// ```
+#[cfg(feature = "uuid-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid-1")))]
+pub mod uuid_1_as_python_legacy_binary {
+    as_legacy_binary_mod!(
+        cfg(feature = "uuid-1"),
+        uuid::Uuid,
+        UuidRepresentation::PythonLegacy
+    );
+}
+
+/// Contains functions to serialize a [`uuid::Uuid`] to a [`crate::Binary`] in the legacy C# driver
+/// UUID format and deserialize [`uuid::Uuid`] from a [`crate::Binary`] in the legacy C# driver
+/// format.
+///
+/// ```rust
+/// # #[cfg(feature = "uuid-1")]
+/// # {
+/// use serde::{Serialize, Deserialize};
+/// use uuid::Uuid;
+/// use bson::serde_helpers::uuid_1_as_c_sharp_legacy_binary;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "uuid_1_as_c_sharp_legacy_binary")]
+///     pub id: Uuid,
+/// }
+/// # }
+/// ```
+#[cfg(feature = "uuid-1")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid-1")))]
+pub mod uuid_1_as_c_sharp_legacy_binary {
+    as_legacy_binary_mod!(
+        cfg(feature = "uuid-1"),
+        uuid::Uuid,
+        UuidRepresentation::CSharpLegacy
+    );
+}
+
+/// Contains functions to serialize a u32 as a bson::Timestamp and deserialize a u32 from a
+/// bson::Timestamp. The u32 should represent seconds since the Unix epoch.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::u32_as_timestamp;
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "u32_as_timestamp")]
+///     pub time: u32,
+/// }
+/// ```
+pub mod u32_as_timestamp {
+    use crate::{Bson, Timestamp};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Serializes a u32 as a bson::Timestamp.
+    pub fn serialize<S: Serializer>(val: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        let timestamp = Bson::Timestamp(Timestamp {
+            time: *val,
+            increment: 0,
+        });
+        timestamp.serialize(serializer)
+    }
+
+    /// Deserializes a u32 from a bson::Timestamp.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u32, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let timestamp = Timestamp::deserialize(deserializer)?;
+        Ok(timestamp.time)
+    }
+}
+
+/// Contains functions to serialize a bson::Timestamp as a u32 and deserialize a bson::Timestamp
+/// from a u32. The u32 should represent seconds since the Unix epoch. Serialization will return an
+/// error if the Timestamp has a non-zero increment.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::timestamp_as_u32, Timestamp};
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "timestamp_as_u32")]
+///     pub timestamp: Timestamp,
+/// }
+/// ```
+pub mod timestamp_as_u32 {
+    use crate::Timestamp;
+    use serde::{ser, Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Serializes a bson::Timestamp as a u32. Returns an error if the conversion is lossy (i.e. the
+    /// Timestamp has a non-zero increment).
+    pub fn serialize<S: Serializer>(val: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        if val.increment != 0 {
+            return Err(ser::Error::custom(
+                "Cannot convert Timestamp with a non-zero increment to u32",
+            ));
+        }
+        serializer.serialize_u32(val.time)
+    }
+
+    /// Deserializes a bson::Timestamp from a u32.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let time = u32::deserialize(deserializer)?;
+        Ok(Timestamp { time, increment: 0 })
+    }
+}
+
+/// Contains functions to serialize the `time` of a bson::Timestamp as a u32 and deserialize a
+/// bson::Timestamp from a u32. The u32 should represent seconds since the Unix epoch.
+///
+/// Unlike [`timestamp_as_u32`], serialization never fails: the increment of the Timestamp is
+/// silently discarded, and deserialized Timestamps always have an increment of zero. Use this only
+/// when the increment is known to be insignificant.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::timestamp_time_as_u32, Timestamp};
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "timestamp_time_as_u32")]
+///     pub timestamp: Timestamp,
+/// }
+/// ```
+pub mod timestamp_time_as_u32 {
+    use crate::Timestamp;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Serializes the time of a bson::Timestamp as a u32, ignoring its increment.
+    pub fn serialize<S: Serializer>(val: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(val.time)
+    }
+
+    /// Deserializes a bson::Timestamp with an increment of zero from a u32.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let time = u32::deserialize(deserializer)?;
+        Ok(Timestamp { time, increment: 0 })
+    }
+}
+
+/// Contains functions to serialize a two-element tuple as a BSON document of the form
+/// `{ "k": <key>, "v": <value> }` and deserialize a two-element tuple from such a document.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::tuple_as_document, Bson};
+/// #[derive(Serialize, Deserialize)]
+/// struct Setting {
+///     #[serde(with = "tuple_as_document")]
+///     pub entry: (String, Bson),
+/// }
+/// ```
+pub mod tuple_as_document {
+    use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct KeyValue<K, V> {
+        k: K,
+        v: V,
+    }
+
+    /// Serializes a `(K, V)` tuple as a `{ "k": <key>, "v": <value> }` document.
+    pub fn serialize<K, V, S>((k, v): &(K, V), serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        V: Serialize,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("KeyValue", 2)?;
+        state.serialize_field("k", k)?;
+        state.serialize_field("v", v)?;
+        state.end()
+    }
+
+    /// Deserializes a `(K, V)` tuple from a `{ "k": <key>, "v": <value> }` document. Errors if
+    /// either field is missing or if any other fields are present.
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<(K, V), D::Error>
+    where
+        K: Deserialize<'de>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let KeyValue { k, v } = KeyValue::deserialize(deserializer)?;
+        Ok((k, v))
+    }
+
+    /// Type converter for serializing a two-element tuple as a `{ "k": <key>, "v": <value> }`
+    /// document using [`serde_with::serde_as`]. This can be used for tuples nested in other
+    /// types, e.g. `Vec<(String, Bson)>`.
+    /// ```rust
+    /// # #[cfg(feature = "serde_with-3")]
+    /// # {
+    /// use bson::{serde_helpers::tuple_as_document, Bson};
+    /// use serde::{Serialize, Deserialize};
+    /// use serde_with::serde_as;
+    /// #[serde_as]
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Settings {
+    ///     #[serde_as(as = "Vec<tuple_as_document::TupleAsDocument>")]
+    ///     pub entries: Vec<(String, Bson)>,
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "serde_with-3")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_with-3")))]
+    pub struct TupleAsDocument;
+
+    #[cfg(feature = "serde_with-3")]
+    impl<K: Serialize, V: Serialize> serde_with::SerializeAs<(K, V)> for TupleAsDocument {
+        fn serialize_as<S>(source: &(K, V), serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize(source, serializer)
+        }
+    }
+
+    #[cfg(feature = "serde_with-3")]
+    impl<'de, K: Deserialize<'de>, V: Deserialize<'de>> serde_with::DeserializeAs<'de, (K, V)>
+        for TupleAsDocument
+    {
+        fn deserialize_as<D>(deserializer: D) -> Result<(K, V), D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize(deserializer)
+        }
+    }
+}
+
+/// Contains functions to serialize a String as a [`Decimal128`](crate::Decimal128) and deserialize
+/// a String from a [`Decimal128`](crate::Decimal128).
+///
+/// The conversion is text-based: serialization parses the string as a decimal value (e.g. `"3.14"`,
+/// `"-0"`, `"NaN"` or `"Infinity"`) and returns an error if it isn't one, and deserialization
+/// produces the canonical string representation of the decimal value. Strings that are already in
+/// canonical form round-trip unchanged.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::string_as_decimal128;
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "string_as_decimal128")]
+///     pub price: String,
+/// }
+/// ```
+pub mod string_as_decimal128 {
+    use crate::Decimal128;
+    use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a String from a [`Decimal128`].
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let decimal = Decimal128::deserialize(deserializer)?;
+        Ok(decimal.to_string())
+    }
+
+    /// Serializes a String as a [`Decimal128`]. Errors if the string is not a valid decimal value.
+    pub fn serialize<S: Serializer>(val: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        let decimal: Decimal128 = val.parse().map_err(|e| {
+            ser::Error::custom(format!("cannot convert {:?} to Decimal128: {}", val, e))
+        })?;
+        decimal.serialize(serializer)
+    }
+}
+
+/// Contains functions to serialize a [`Binary`](crate::Binary) as a hex-encoded string and
+/// deserialize a [`Binary`](crate::Binary) with the
+/// [`Generic`](crate::spec::BinarySubtype::Generic) subtype from one.
+///
+/// The subtype is not stored, so use [`binary_as_hex_string_with_subtype`] if it needs to be
+/// preserved. Deserialization returns an error if the string is not valid hex.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::binary_as_hex_string, Binary};
+/// #[derive(Serialize, Deserialize)]
+/// struct File {
+///     #[serde(with = "binary_as_hex_string")]
+///     pub checksum: Binary,
+/// }
+/// ```
+pub mod binary_as_hex_string {
+    use crate::{spec::BinarySubtype, Binary};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a generic [`Binary`] from a hex-encoded string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Binary, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        let bytes = hex::decode(&hex)
+            .map_err(|e| de::Error::custom(format!("cannot convert {:?} to Binary: {}", hex, e)))?;
+        Ok(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes,
+        })
+    }
+
+    /// Serializes a [`Binary`] as a hex-encoded string, discarding its subtype.
+    pub fn serialize<S: Serializer>(val: &Binary, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(&val.bytes))
+    }
+}
+
+/// Contains functions to serialize a [`Binary`](crate::Binary) as a string of the form
+/// `"<subtype>:<data>"` and deserialize a [`Binary`](crate::Binary) from one, where `<subtype>`
+/// is the subtype as a two-digit hex byte and `<data>` is the hex-encoded payload (e.g.
+/// `"80:0a0b"`).
+///
+/// Deserialization returns an error if the string is not of this form.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::binary_as_hex_string_with_subtype, Binary};
+/// #[derive(Serialize, Deserialize)]
+/// struct Blob {
+///     #[serde(with = "binary_as_hex_string_with_subtype")]
+///     pub data: Binary,
+/// }
+/// ```
+pub mod binary_as_hex_string_with_subtype {
+    use crate::{spec::BinarySubtype, Binary};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a [`Binary`] from a `"<subtype>:<data>"` string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Binary, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let val = String::deserialize(deserializer)?;
+        let invalid = |e: &dyn std::fmt::Display| {
+            de::Error::custom(format!("cannot convert {:?} to Binary: {}", val, e))
+        };
+        let (subtype, data) = val
+            .split_once(':')
+            .ok_or_else(|| invalid(&"expected a string of the form \"<subtype>:<data>\""))?;
+        let subtype = match hex::decode(subtype).map_err(|e| invalid(&e))?.as_slice() {
+            [subtype] => BinarySubtype::from(*subtype),
+            _ => return Err(invalid(&"the subtype must be a single hex-encoded byte")),
+        };
+        let bytes = hex::decode(data).map_err(|e| invalid(&e))?;
+        Ok(Binary { subtype, bytes })
+    }
+
+    /// Serializes a [`Binary`] as a `"<subtype>:<data>"` string.
+    pub fn serialize<S: Serializer>(val: &Binary, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!(
+            "{:02x}:{}",
+            u8::from(val.subtype),
+            hex::encode(&val.bytes)
+        ))
+    }
+}
+
+/// Contains functions to serialize a String as a BSON string and deserialize a String from any
+/// scalar BSON value.
+///
+/// This is useful for fields that are stored inconsistently across a collection, such as an
+/// identifier that is sometimes an `Int32`, sometimes an `Int64` and sometimes a `String`. When
+/// deserializing, numbers, booleans, [`ObjectId`](crate::oid::ObjectId)s and
+/// [`Decimal128`](crate::Decimal128)s are converted to their [`Display`](std::fmt::Display)
+/// representation (e.g. `42` becomes `"42"` and `2.5` becomes `"2.5"`), while documents, arrays
+/// and other non-scalar values produce an error. Serialization always produces a BSON string.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::serde_helpers::coerce_to_string;
+/// #[derive(Serialize, Deserialize)]
+/// struct Account {
+///     #[serde(with = "coerce_to_string")]
+///     pub external_id: String,
+/// }
+/// ```
+pub mod coerce_to_string {
+    use crate::Bson;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a String from any scalar BSON value.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Bson::deserialize(deserializer)? {
+            Bson::String(s) | Bson::Symbol(s) => Ok(s),
+            Bson::Int32(i) => Ok(i.to_string()),
+            Bson::Int64(i) => Ok(i.to_string()),
+            Bson::Double(d) => Ok(d.to_string()),
+            Bson::Boolean(b) => Ok(b.to_string()),
+            Bson::Decimal128(d) => Ok(d.to_string()),
+            Bson::ObjectId(oid) => Ok(oid.to_hex()),
+            other => Err(de::Error::custom(format!(
+                "cannot coerce {} to a string",
+                other.type_name()
+            ))),
+        }
+    }
+
+    /// Serializes a String as a BSON string.
+    pub fn serialize<S: Serializer>(val: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(val)
+    }
+}
+
+/// Contains functions to serialize an `Option<Document>` as fields merged into the containing
+/// struct and deserialize it from the fields not claimed by any other struct field. This is
+/// intended to be used together with `#[serde(flatten)]`.
+///
+/// When serializing, `Some` writes each of the inner document's key-value pairs as a sibling of
+/// the other struct fields, and `None` writes nothing. When deserializing, any leftover fields are
+/// collected into `Some`, or `None` if there are none.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{doc, serde_helpers::flatten_optional, Document};
+/// #[derive(Serialize, Deserialize)]
+/// struct Update {
+///     pub name: String,
+///     #[serde(flatten, with = "flatten_optional")]
+///     pub extra: Option<Document>,
+/// }
+///
+/// let update = Update { name: "x".to_string(), extra: Some(doc! { "count": 1 }) };
+/// assert_eq!(bson::serialize_to_document(&update)?, doc! { "name": "x", "count": 1 });
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub mod flatten_optional {
+    use crate::Document;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Serializes the key-value pairs of the document, if any, as a map.
+    pub fn serialize<S: Serializer>(
+        val: &Option<Document>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match val {
+            Some(doc) => serializer.collect_map(doc),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes a document from a map, returning `None` if the map is empty.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Document>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc = Option::<Document>::deserialize(deserializer)?;
+        Ok(doc.filter(|doc| !doc.is_empty()))
+    }
+}
+
+/// Helpers for serializing byte buffers as BSON binary values.
+pub mod binary {
+    /// Contains functions to serialize a `Vec<u8>` as a BSON binary value with the
+    /// [`Generic`](crate::spec::BinarySubtype::Generic) subtype and deserialize a `Vec<u8>` from
+    /// one. Deserialization fails if the binary value has any other subtype.
+    ///
+    /// ```rust
+    /// # use serde::{Serialize, Deserialize};
+    /// # use bson::serde_helpers::binary;
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Attachment {
+    ///     pub name: String,
+    ///     #[serde(with = "binary::as_generic_bytes")]
+    ///     pub data: Vec<u8>,
+    /// }
+    /// ```
+    pub mod as_generic_bytes {
+        use crate::{spec::BinarySubtype, Binary, RawBinaryRef};
+        use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+        use std::result::Result;
+
+        /// Serializes a byte slice as a BSON binary value with the generic subtype.
+        pub fn serialize<S: Serializer>(val: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            RawBinaryRef {
+                subtype: BinarySubtype::Generic,
+                bytes: val,
+            }
+            .serialize(serializer)
+        }
+
+        /// Deserializes a `Vec<u8>` from a BSON binary value with the generic subtype.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let binary = Binary::deserialize(deserializer)?;
+            match binary.subtype {
+                BinarySubtype::Generic => Ok(binary.bytes),
+                other => Err(de::Error::custom(format!(
+                    "expected binary subtype {:?}, got {:?}",
+                    BinarySubtype::Generic,
+                    other
+                ))),
+            }
+        }
+    }
+}
+
+/// Contains functions to serialize a [`Document`](crate::Document) as an array of two-element
+/// `[key, value]` arrays, preserving the order of the keys, and deserialize it from one.
+///
+/// This is equivalent to [`document::AsVecPairs`], but does not require the `serde_with-3`
+/// feature. If the same key appears in more than one pair when deserializing, the last value wins.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::document_as_vec_of_pairs, Document};
+/// #[derive(Serialize, Deserialize)]
+/// struct Item {
+///     #[serde(with = "document_as_vec_of_pairs")]
+///     pub fields: Document,
+/// }
+/// ```
+pub mod document_as_vec_of_pairs {
+    use crate::{Bson, Document};
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::result::Result;
+
+    /// Deserializes a [`Document`] from an array of `[key, value]` arrays.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Document, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pairs = Vec::<(String, Bson)>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+
+    /// Serializes a [`Document`] as an array of `[key, value]` arrays.
+    pub fn serialize<S: Serializer>(val: &Document, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(val.iter())
+    }
+}
+
+/// Contains functions to serialize a `Vec<HashMap<String, Bson>>` as an array of BSON documents
+/// and deserialize it from one, such as the results of an aggregation.
+///
+/// Since a [`HashMap`](std::collections::HashMap) has no order, the keys of each document are
+/// sorted when serializing so that the output is deterministic. Deserializing fails if any
+/// element of the array is not a document.
+///
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{serde_helpers::array_of_documents_as_hashmap, Bson};
+/// #[derive(Serialize, Deserialize)]
+/// struct Results {
+///     #[serde(with = "array_of_documents_as_hashmap")]
+///     pub rows: Vec<HashMap<String, Bson>>,
+/// }
+/// ```
+pub mod array_of_documents_as_hashmap {
+    use crate::{Bson, Document};
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::{collections::HashMap, result::Result};
+
+    /// Deserializes a `Vec<HashMap<String, Bson>>` from an array of BSON documents.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<HashMap<String, Bson>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let docs = Vec::<Document>::deserialize(deserializer)?;
+        Ok(docs
+            .into_iter()
+            .map(|doc| doc.into_iter().collect())
+            .collect())
+    }
+
+    /// Serializes a `Vec<HashMap<String, Bson>>` as an array of BSON documents with sorted keys.
+    pub fn serialize<S: Serializer>(
+        val: &[HashMap<String, Bson>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(val.iter().map(|map| {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Document>()
+        }))
+    }
+}
+
+/// Contains functions to deserialize a value as `T::default()` when it is BSON null. Serialization
+/// is unaffected.
+///
+/// Missing fields are not covered by this module; combine it with `#[serde(default)]` to treat
+/// missing fields the same way.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{doc, serde_helpers::null_as_default};
+/// #[derive(Serialize, Deserialize)]
+/// struct Profile {
+///     #[serde(with = "null_as_default")]
+///     pub tags: Vec<String>,
+///     #[serde(default, with = "null_as_default")]
+///     pub visits: i32,
+/// }
+///
+/// let profile: Profile = bson::deserialize_from_document(doc! { "tags": null })?;
+/// assert!(profile.tags.is_empty());
+/// assert_eq!(profile.visits, 0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub mod null_as_default {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Serializes the value as-is.
+    pub fn serialize<T, S>(val: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        val.serialize(serializer)
+    }
+
+    /// Deserializes a `T`, or returns `T::default()` if the value is null.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Deserialize<'de> + Default,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+    }
+}
+
+/// Contains functions to serialize an `Option<String>` as a BSON string or null and deserialize it
+/// from one, treating empty strings as `None`.
+///
+/// This is useful for collections where missing values have been stored as empty strings rather
+/// than null. Note that `Some(String::new())` is serialized as an empty string, and so will be
+/// deserialized as `None`.
+///
+/// ```rust
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{doc, serde_helpers::option_string_empty_as_none};
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "option_string_empty_as_none")]
+///     pub nickname: Option<String>,
 /// }
-/// # }
+///
+/// let user: User = bson::deserialize_from_document(doc! { "nickname": "" })?;
+/// assert_eq!(user.nickname, None);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[cfg(feature = "uuid-1")]
-#[cfg_attr(docsrs, doc(cfg(feature = "uuid-1")))]
-pub mod uuid_1_as_python_legacy_binary {
-    as_legacy_binary_mod!(
-        cfg(feature = "uuid-1"),
-        uuid::Uuid,
-        UuidRepresentation::PythonLegacy
-    );
+pub mod option_string_empty_as_none {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Deserializes an `Option<String>` from a BSON string or null, returning `None` for an empty
+    /// string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<String>::deserialize(deserializer)?.filter(|s| !s.is_empty()))
+    }
+
+    /// Serializes an `Option<String>` as a BSON string, or null if it is `None`.
+    pub fn serialize<S: Serializer>(
+        val: &Option<String>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        val.serialize(serializer)
+    }
 }
 
-/// Contains functions to serialize a [`uuid::Uuid`] to a [`crate::Binary`] in the legacy C# driver
-/// UUID format and deserialize [`uuid::Uuid`] from a [`crate::Binary`] in the legacy C# driver
-/// format.
+/// Contains functions to serialize an `Option<DateTime>` as an RFC 3339 (ISO 8601) formatted
+/// string or null, and deserialize an `Option<DateTime>` from one. This does not require the
+/// `serde_with-3` feature.
 ///
 /// ```rust
-/// # #[cfg(feature = "uuid-1")]
-/// # {
-/// use serde::{Serialize, Deserialize};
-/// use uuid::Uuid;
-/// use bson::serde_helpers::uuid_1_as_c_sharp_legacy_binary;
-///
+/// # use serde::{Serialize, Deserialize};
+/// # use bson::{doc, serde_helpers::datetime_option_as_rfc3339_string, DateTime};
 /// #[derive(Serialize, Deserialize)]
-/// struct Item {
-///     #[serde(with = "uuid_1_as_c_sharp_legacy_binary")]
-///     pub id: Uuid,
+/// struct Task {
+///     #[serde(with = "datetime_option_as_rfc3339_string")]
+///     pub completed_at: Option<DateTime>,
 /// }
-/// # }
+///
+/// let task = Task { completed_at: Some(DateTime::from_millis(0)) };
+/// assert_eq!(
+///     bson::serialize_to_document(&task)?,
+///     doc! { "completed_at": "1970-01-01T00:00:00Z" },
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[cfg(feature = "uuid-1")]
-#[cfg_attr(docsrs, doc(cfg(feature = "uuid-1")))]
-pub mod uuid_1_as_c_sharp_legacy_binary {
-    as_legacy_binary_mod!(
-        cfg(feature = "uuid-1"),
-        uuid::Uuid,
-        UuidRepresentation::CSharpLegacy
-    );
+pub mod datetime_option_as_rfc3339_string {
+    use crate::DateTime;
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+    use std::result::Result;
+
+    /// Deserializes an `Option<DateTime>` from an RFC 3339 formatted string or null.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|string| {
+                DateTime::parse_rfc3339_str(&string).map_err(|e| {
+                    de::Error::custom(format!(
+                        "cannot parse RFC 3339 datetime from \"{}\": {}",
+                        string, e
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// Serializes an `Option<DateTime>` as an RFC 3339 formatted string, or null if it is `None`.
+    pub fn serialize<S: Serializer>(
+        val: &Option<DateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        val.map(|date| {
+            date.try_to_rfc3339_string().map_err(|e| {
+                ser::Error::custom(format!(
+                    "cannot format DateTime {} as RFC 3339 string: {}",
+                    date, e
+                ))
+            })
+        })
+        .transpose()?
+        .serialize(serializer)
+    }
 }
 
-/// Contains functions to serialize a u32 as a bson::Timestamp and deserialize a u32 from a
-/// bson::Timestamp. The u32 should represent seconds since the Unix epoch.
+/// Contains functions to serialize a [`RawDocumentBuf`](crate::RawDocumentBuf) as an embedded BSON
+/// document and deserialize a [`RawDocumentBuf`](crate::RawDocumentBuf) from one. When
+/// deserializing from BSON bytes, the document is copied without being parsed into a
+/// [`Document`](crate::Document).
 ///
 /// ```rust
 /// # use serde::{Serialize, Deserialize};
-/// # use bson::serde_helpers::u32_as_timestamp;
+/// # use bson::{serde_helpers::raw_document, RawDocumentBuf};
 /// #[derive(Serialize, Deserialize)]
-/// struct Event {
-///     #[serde(with = "u32_as_timestamp")]
-///     pub time: u32,
+/// struct Envelope {
+///     pub kind: String,
+///     #[serde(with = "raw_document")]
+///     pub payload: RawDocumentBuf,
 /// }
 /// ```
-pub mod u32_as_timestamp {
-    use crate::{Bson, Timestamp};
+pub mod raw_document {
+    use crate::{RawDocument, RawDocumentBuf};
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use std::result::Result;
 
-    /// Serializes a u32 as a bson::Timestamp.
-    pub fn serialize<S: Serializer>(val: &u32, serializer: S) -> Result<S::Ok, S::Error> {
-        let timestamp = Bson::Timestamp(Timestamp {
-            time: *val,
-            increment: 0,
-        });
-        timestamp.serialize(serializer)
+    /// Serializes a [`RawDocument`] as an embedded BSON document.
+    pub fn serialize<S: Serializer>(val: &RawDocument, serializer: S) -> Result<S::Ok, S::Error> {
+        val.serialize(serializer)
     }
 
-    /// Deserializes a u32 from a bson::Timestamp.
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<u32, D::Error>
+    /// Deserializes a [`RawDocumentBuf`] from an embedded BSON document.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RawDocumentBuf, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let timestamp = Timestamp::deserialize(deserializer)?;
-        Ok(timestamp.time)
+        RawDocumentBuf::deserialize(deserializer)
     }
 }
 
-/// Contains functions to serialize a bson::Timestamp as a u32 and deserialize a bson::Timestamp
-/// from a u32. The u32 should represent seconds since the Unix epoch. Serialization will return an
-/// error if the Timestamp has a non-zero increment.
+/// Contains functions to serialize a [`RawArrayBuf`](crate::RawArrayBuf) as a BSON array and
+/// deserialize a [`RawArrayBuf`](crate::RawArrayBuf) from one. When deserializing from BSON bytes,
+/// the array is copied without being parsed into a [`Vec<Bson>`](crate::Bson).
 ///
 /// ```rust
 /// # use serde::{Serialize, Deserialize};
-/// # use bson::{serde_helpers::timestamp_as_u32, Timestamp};
+/// # use bson::{serde_helpers::raw_array, RawArrayBuf};
 /// #[derive(Serialize, Deserialize)]
-/// struct Item {
-///     #[serde(with = "timestamp_as_u32")]
-///     pub timestamp: Timestamp,
+/// struct Batch {
+///     pub id: i32,
+///     #[serde(with = "raw_array")]
+///     pub items: RawArrayBuf,
 /// }
 /// ```
-pub mod timestamp_as_u32 {
-    use crate::Timestamp;
-    use serde::{ser, Deserialize, Deserializer, Serializer};
+pub mod raw_array {
+    use crate::{RawArray, RawArrayBuf};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
     use std::result::Result;
 
-    /// Serializes a bson::Timestamp as a u32. Returns an error if the conversion is lossy (i.e. the
-    /// Timestamp has a non-zero increment).
-    pub fn serialize<S: Serializer>(val: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
-        if val.increment != 0 {
-            return Err(ser::Error::custom(
-                "Cannot convert Timestamp with a non-zero increment to u32",
-            ));
-        }
-        serializer.serialize_u32(val.time)
+    /// Serializes a [`RawArray`] as a BSON array.
+    pub fn serialize<S: Serializer>(val: &RawArray, serializer: S) -> Result<S::Ok, S::Error> {
+        val.serialize(serializer)
     }
 
-    /// Deserializes a bson::Timestamp from a u32.
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Timestamp, D::Error>
+    /// Deserializes a [`RawArrayBuf`] from a BSON array.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RawArrayBuf, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let time = u32::deserialize(deserializer)?;
-        Ok(Timestamp { time, increment: 0 })
+        RawArrayBuf::deserialize(deserializer)
     }
 }
 
@@ -795,3 +2179,335 @@ where
         self.deref_mut().as_mut()
     }
 }
+
+/// Wrapper type for deferring the deserialization of a value to `T`.
+///
+/// Deserializing a `RawBsonValue<T>` captures the value as a [`RawBson`](crate::RawBson) without
+/// interpreting it as a `T`; [`RawBsonValue::parse`] can then be called to deserialize it once
+/// it is actually needed. When deserializing from BSON bytes, the value is copied without being
+/// parsed.
+///
+/// Serializing a `RawBsonValue<T>` writes the captured value as-is.
+///
+/// ```rust
+/// # use serde::Deserialize;
+/// # use bson::{doc, serde_helpers::RawBsonValue};
+/// #[derive(Deserialize)]
+/// struct Event {
+///     kind: String,
+///     payload: RawBsonValue<Vec<i32>>,
+/// }
+///
+/// let bytes = bson::serialize_to_vec(&doc! { "kind": "numbers", "payload": [1, 2, 3] })?;
+/// let event: Event = bson::deserialize_from_slice(&bytes)?;
+/// assert_eq!(event.kind, "numbers");
+/// assert_eq!(event.payload.parse()?, vec![1, 2, 3]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct RawBsonValue<T> {
+    raw: crate::RawBson,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> RawBsonValue<T> {
+    /// Wraps an already captured value.
+    pub fn new(raw: crate::RawBson) -> Self {
+        Self {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the captured value.
+    pub fn as_raw(&self) -> &crate::RawBson {
+        &self.raw
+    }
+
+    /// Returns the captured value.
+    pub fn into_raw(self) -> crate::RawBson {
+        self.raw
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> RawBsonValue<T> {
+    /// Deserializes the captured value as a `T`.
+    pub fn parse(&self) -> crate::error::Result<T> {
+        crate::deserialize_from_bson(self.raw.to_bson()?)
+    }
+}
+
+impl<T> Clone for RawBsonValue<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.raw.clone())
+    }
+}
+
+impl<T> PartialEq for RawBsonValue<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T> std::fmt::Debug for RawBsonValue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RawBsonValue").field(&self.raw).finish()
+    }
+}
+
+impl<T> From<crate::RawBson> for RawBsonValue<T> {
+    fn from(raw: crate::RawBson) -> Self {
+        Self::new(raw)
+    }
+}
+
+impl<T> Serialize for RawBsonValue<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.raw.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for RawBsonValue<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::RawBson::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Wrapper type for deserializing a struct while ignoring any fields it does not declare, even if
+/// it was derived with `#[serde(deny_unknown_fields)]`.
+///
+/// This only applies to the fields of the wrapped type itself; any nested structs that deny
+/// unknown fields will continue to do so.
+///
+/// This wrapper type has no impact on serialization. Serializing a `SkipUnknownFields<T>` will call
+/// the `serialize` method for the wrapped `T`.
+///
+/// ```rust
+/// # use serde::Deserialize;
+/// # use bson::{doc, serde_helpers::SkipUnknownFields};
+/// #[derive(Deserialize)]
+/// #[serde(deny_unknown_fields)]
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let doc = doc! { "name": "x", "extra": 1 };
+/// assert!(bson::deserialize_from_document::<Config>(doc.clone()).is_err());
+/// let config: SkipUnknownFields<Config> = bson::deserialize_from_document(doc)?;
+/// assert_eq!(config.name, "x");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+#[repr(transparent)]
+pub struct SkipUnknownFields<T>(pub T);
+
+impl<T: Serialize> Serialize for SkipUnknownFields<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SkipUnknownFields<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(skip_unknown_fields::FieldFilter(deserializer)).map(SkipUnknownFields)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for SkipUnknownFields<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> From<T> for SkipUnknownFields<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for SkipUnknownFields<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for SkipUnknownFields<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, R> AsRef<R> for SkipUnknownFields<T>
+where
+    R: ?Sized,
+    <SkipUnknownFields<T> as Deref>::Target: AsRef<R>,
+{
+    fn as_ref(&self) -> &R {
+        self.deref().as_ref()
+    }
+}
+
+impl<T, R: ?Sized> AsMut<R> for SkipUnknownFields<T>
+where
+    <SkipUnknownFields<T> as Deref>::Target: AsMut<R>,
+{
+    fn as_mut(&mut self) -> &mut R {
+        self.deref_mut().as_mut()
+    }
+}
+
+mod skip_unknown_fields {
+    use serde::de::{
+        DeserializeSeed,
+        Deserializer,
+        IgnoredAny,
+        IntoDeserializer,
+        MapAccess,
+        SeqAccess,
+        Visitor,
+    };
+    use std::result::Result;
+
+    /// Wraps a deserializer, forwarding everything to it except that the keys of a struct which
+    /// are not among its declared fields are skipped.
+    pub(super) struct FieldFilter<D>(pub(super) D);
+
+    macro_rules! forward {
+        ($($name:ident($($arg:ident: $ty:ty),*);)*) => {
+            $(
+                fn $name<V>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error>
+                where
+                    V: Visitor<'de>,
+                {
+                    self.0.$name($($arg,)* visitor)
+                }
+            )*
+        };
+    }
+
+    impl<'de, D: Deserializer<'de>> Deserializer<'de> for FieldFilter<D> {
+        type Error = D::Error;
+
+        forward! {
+            deserialize_any();
+            deserialize_bool();
+            deserialize_i8();
+            deserialize_i16();
+            deserialize_i32();
+            deserialize_i64();
+            deserialize_i128();
+            deserialize_u8();
+            deserialize_u16();
+            deserialize_u32();
+            deserialize_u64();
+            deserialize_u128();
+            deserialize_f32();
+            deserialize_f64();
+            deserialize_char();
+            deserialize_str();
+            deserialize_string();
+            deserialize_bytes();
+            deserialize_byte_buf();
+            deserialize_option();
+            deserialize_unit();
+            deserialize_unit_struct(name: &'static str);
+            deserialize_newtype_struct(name: &'static str);
+            deserialize_seq();
+            deserialize_tuple(len: usize);
+            deserialize_tuple_struct(name: &'static str, len: usize);
+            deserialize_map();
+            deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+            deserialize_identifier();
+            deserialize_ignored_any();
+        }
+
+        fn deserialize_struct<V>(
+            self,
+            name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, D::Error>
+        where
+            V: Visitor<'de>,
+        {
+            self.0
+                .deserialize_struct(name, fields, StructVisitor { visitor, fields })
+        }
+
+        fn is_human_readable(&self) -> bool {
+            self.0.is_human_readable()
+        }
+    }
+
+    struct StructVisitor<V> {
+        visitor: V,
+        fields: &'static [&'static str],
+    }
+
+    impl<'de, V: Visitor<'de>> Visitor<'de> for StructVisitor<V> {
+        type Value = V::Value;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            self.visitor.expecting(formatter)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            self.visitor.visit_map(KnownFields {
+                map,
+                fields: self.fields,
+            })
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            self.visitor.visit_seq(seq)
+        }
+    }
+
+    struct KnownFields<A> {
+        map: A,
+        fields: &'static [&'static str],
+    }
+
+    impl<'de, A: MapAccess<'de>> MapAccess<'de> for KnownFields<A> {
+        type Error = A::Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            while let Some(key) = self.map.next_key::<String>()? {
+                if self.fields.contains(&key.as_str()) {
+                    return seed.deserialize(key.into_deserializer()).map(Some);
+                }
+                self.map.next_value::<IgnoredAny>()?;
+            }
+            Ok(None)
+        }
+
+        fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, A::Error>
+        where
+            S: DeserializeSeed<'de>,
+        {
+            self.map.next_value_seed(seed)
+        }
+    }
+}
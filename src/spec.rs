@@ -23,6 +23,8 @@
 
 use std::{convert::From, fmt};
 
+use crate::error::{Error, Result};
+
 impl fmt::LowerHex for BinarySubtype {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value: u8 = (*self).into();
@@ -119,6 +121,35 @@ pub enum ElementType {
 }
 
 impl ElementType {
+    /// The name used for values of this type in error messages, matching [`Bson::type_name`].
+    ///
+    /// [`Bson::type_name`]: crate::Bson::type_name
+    pub(crate) fn type_name(self) -> &'static str {
+        match self {
+            ElementType::Double => "Double",
+            ElementType::String => "String",
+            ElementType::EmbeddedDocument => "Document",
+            ElementType::Array => "Array",
+            ElementType::Binary => "Binary",
+            ElementType::Undefined => "Undefined",
+            ElementType::ObjectId => "ObjectId",
+            ElementType::Boolean => "Boolean",
+            ElementType::DateTime => "DateTime",
+            ElementType::Null => "Null",
+            ElementType::RegularExpression => "RegularExpression",
+            ElementType::DbPointer => "DbPointer",
+            ElementType::JavaScriptCode => "JavaScriptCode",
+            ElementType::Symbol => "Symbol",
+            ElementType::JavaScriptCodeWithScope => "JavaScriptCodeWithScope",
+            ElementType::Int32 => "Int32",
+            ElementType::Timestamp => "Timestamp",
+            ElementType::Int64 => "Int64",
+            ElementType::Decimal128 => "Decimal128",
+            ElementType::MaxKey => "MaxKey",
+            ElementType::MinKey => "MinKey",
+        }
+    }
+
     /// Attempt to convert from a `u8`.
     #[inline]
     pub fn from(tag: u8) -> Option<ElementType> {
@@ -234,3 +265,25 @@ impl From<u8> for BinarySubtype {
         }
     }
 }
+
+impl BinarySubtype {
+    /// Creates a [`BinarySubtype::UserDefined`] from the given byte, returning an error if it is
+    /// not in the user-defined range of `0x80` to `0xFF`.
+    ///
+    /// ```
+    /// use bson::spec::BinarySubtype;
+    ///
+    /// assert_eq!(BinarySubtype::user_defined(0x80)?, BinarySubtype::UserDefined(0x80));
+    /// assert!(BinarySubtype::user_defined(0x7F).is_err());
+    /// # Ok::<(), bson::error::Error>(())
+    /// ```
+    pub fn user_defined(byte: u8) -> Result<BinarySubtype> {
+        if byte < BINARY_SUBTYPE_USER_DEFINED {
+            return Err(Error::binary(format!(
+                "binary subtype {:#04x} is not in the user-defined range {:#04x} to 0xff",
+                byte, BINARY_SUBTYPE_USER_DEFINED
+            )));
+        }
+        Ok(BinarySubtype::UserDefined(byte))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{spec::BinarySubtype, tests::LOCK};
+use crate::{error::ErrorKind, spec::BinarySubtype, tests::LOCK};
 
 #[test]
 fn from_u8() {
@@ -12,3 +12,20 @@ fn from_u8() {
     assert_eq!(BinarySubtype::from(0x80), BinarySubtype::UserDefined(0x80));
     assert_eq!(BinarySubtype::from(0xFF), BinarySubtype::UserDefined(0xFF));
 }
+
+#[test]
+fn user_defined() {
+    let _guard = LOCK.run_concurrently();
+    assert_eq!(
+        BinarySubtype::user_defined(0x80).unwrap(),
+        BinarySubtype::UserDefined(0x80)
+    );
+    assert_eq!(
+        BinarySubtype::user_defined(0xFF).unwrap(),
+        BinarySubtype::UserDefined(0xFF)
+    );
+    for byte in [0x00, 0x04, 0x7F] {
+        let error = BinarySubtype::user_defined(byte).unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::Binary { .. }), "{}", byte);
+    }
+}
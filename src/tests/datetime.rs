@@ -26,6 +26,31 @@ fn invalid_rfc3339_to_datetime() {
     assert!(crate::DateTime::parse_rfc3339_str(c).is_err());
 }
 
+#[test]
+fn iso8601_to_datetime() {
+    let _guard = LOCK.run_concurrently();
+
+    let expected = crate::DateTime::from_millis(1_591_700_287_095);
+
+    // sub-millisecond precision is truncated
+    let micros = crate::DateTime::parse_iso8601_str("2020-06-09T10:58:07.095123Z").unwrap();
+    assert_eq!(micros, expected);
+    let nanos = crate::DateTime::parse_iso8601_str("2020-06-09T10:58:07.095999999Z").unwrap();
+    assert_eq!(nanos, expected);
+
+    let offset = crate::DateTime::parse_iso8601_str("2020-06-09T16:28:07.095+05:30").unwrap();
+    assert_eq!(offset, expected);
+
+    let zulu = crate::DateTime::parse_iso8601_str("2020-06-09T10:58:07Z").unwrap();
+    assert_eq!(zulu, crate::DateTime::from_millis(1_591_700_287_000));
+
+    let no_fraction = crate::DateTime::parse_iso8601_str("2020-06-09T10:58:07-01:00").unwrap();
+    assert_eq!(no_fraction, crate::DateTime::from_millis(1_591_703_887_000));
+
+    assert!(crate::DateTime::parse_iso8601_str("2020-06-09T10:62:07Z").is_err());
+    assert!(crate::DateTime::parse_iso8601_str("not a date").is_err());
+}
+
 #[test]
 fn datetime_to_rfc3339() {
     assert_eq!(
@@ -59,4 +84,187 @@ fn duration_since() {
     );
     assert!(date1.checked_duration_since(date2).is_none());
     assert_eq!(date1.saturating_duration_since(date2), Duration::ZERO);
+    assert_eq!(
+        crate::DateTime::MAX.checked_duration_since(crate::DateTime::MIN),
+        Some(Duration::from_millis(u64::MAX))
+    );
+}
+
+#[test]
+fn checked_duration_arithmetic() {
+    let _guard = LOCK.run_concurrently();
+
+    let date = crate::DateTime::from_millis(1_000);
+    assert_eq!(
+        date.checked_add_std_duration(Duration::from_secs(1)),
+        Some(crate::DateTime::from_millis(2_000))
+    );
+    // sub-millisecond precision is ignored
+    assert_eq!(
+        date.checked_add_std_duration(Duration::from_micros(1_999)),
+        Some(crate::DateTime::from_millis(1_001))
+    );
+    assert_eq!(date.checked_add_std_duration(Duration::MAX), None);
+    assert_eq!(
+        crate::DateTime::MAX.checked_add_std_duration(Duration::from_millis(1)),
+        None
+    );
+    assert_eq!(
+        crate::DateTime::MIN.checked_add_std_duration(Duration::from_millis(u64::MAX)),
+        Some(crate::DateTime::MAX)
+    );
+
+    // subtracting across the Unix epoch
+    assert_eq!(
+        date.checked_sub_std_duration(Duration::from_secs(3)),
+        Some(crate::DateTime::from_millis(-2_000))
+    );
+    assert_eq!(date.checked_sub_std_duration(Duration::MAX), None);
+    assert_eq!(
+        crate::DateTime::MIN.checked_sub_std_duration(Duration::from_millis(1)),
+        None
+    );
+    assert_eq!(
+        crate::DateTime::from_millis(0).checked_sub_std_duration(Duration::from_millis(1 << 63)),
+        Some(crate::DateTime::MIN)
+    );
+    assert_eq!(
+        crate::DateTime::MAX.checked_sub_std_duration(Duration::from_millis(u64::MAX)),
+        Some(crate::DateTime::MIN)
+    );
+}
+
+#[test]
+fn calendar_parts() {
+    let _guard = LOCK.run_concurrently();
+
+    let epoch = crate::DateTime::from_millis(0);
+    assert_eq!(epoch.date_parts(), (1970, 1, 1));
+    assert_eq!(epoch.day_of_week_iso(), 4);
+    assert_eq!(epoch.week_number_iso(), 1);
+
+    // the last millisecond before the epoch
+    let before_epoch = crate::DateTime::from_millis(-1);
+    assert_eq!(before_epoch.date_parts(), (1969, 12, 31));
+    assert_eq!(before_epoch.day_of_week_iso(), 3);
+    assert_eq!(before_epoch.week_number_iso(), 1);
+
+    // a Sunday that belongs to the last ISO week of 2022
+    let new_year = crate::DateTime::parse_rfc3339_str("2023-01-01T12:00:00Z").unwrap();
+    assert_eq!(new_year.date_parts(), (2023, 1, 1));
+    assert_eq!(new_year.day_of_week_iso(), 7);
+    assert_eq!(new_year.week_number_iso(), 52);
+
+    let leap_day = crate::DateTime::parse_rfc3339_str("2024-02-29T23:59:59.999Z").unwrap();
+    assert_eq!(leap_day.date_parts(), (2024, 2, 29));
+    assert_eq!(leap_day.day_of_week_iso(), 4);
+    assert_eq!(leap_day.week_number_iso(), 9);
+
+    // 2020 has 53 ISO weeks, the last of which extends into 2021
+    let long_year = crate::DateTime::parse_rfc3339_str("2021-01-03T00:00:00Z").unwrap();
+    assert_eq!(long_year.week_number_iso(), 53);
+    // a Tuesday that belongs to the first ISO week of 2025
+    let short_year = crate::DateTime::parse_rfc3339_str("2024-12-31T00:00:00Z").unwrap();
+    assert_eq!(short_year.week_number_iso(), 1);
+
+    // agrees with the `time` crate for every day between 1900 and 2100
+    for day in -25_567..47_482 {
+        let dt = crate::DateTime::from_millis(day * 24 * 60 * 60 * 1000);
+        let odt = dt.to_time_0_3();
+        assert_eq!(
+            dt.date_parts(),
+            (odt.year(), odt.month() as u8, odt.day()),
+            "{}",
+            dt
+        );
+        assert_eq!(dt.day_of_week_iso(), odt.weekday().number_from_monday());
+        assert_eq!(dt.week_number_iso(), odt.iso_week());
+    }
+
+    // the whole representable range is supported
+    assert!(crate::DateTime::MIN.date_parts().0 < -292_000_000);
+    assert!(crate::DateTime::MAX.date_parts().0 > 292_000_000);
+    for dt in [crate::DateTime::MIN, crate::DateTime::MAX] {
+        assert!((1..=7).contains(&dt.day_of_week_iso()));
+        assert!((1..=53).contains(&dt.week_number_iso()));
+    }
+}
+
+#[test]
+fn format_custom() {
+    let _guard = LOCK.run_concurrently();
+
+    let dt = crate::DateTime::parse_rfc3339_str("2020-06-09T10:58:07.095Z").unwrap();
+    assert_eq!(dt.format_custom("%Y").unwrap(), "2020");
+    assert_eq!(dt.format_custom("%m").unwrap(), "06");
+    assert_eq!(dt.format_custom("%d").unwrap(), "09");
+    assert_eq!(dt.format_custom("%H").unwrap(), "10");
+    assert_eq!(dt.format_custom("%M").unwrap(), "58");
+    assert_eq!(dt.format_custom("%S").unwrap(), "07");
+    assert_eq!(dt.format_custom("%3f").unwrap(), "095");
+    assert_eq!(dt.format_custom("100%%").unwrap(), "100%");
+    assert_eq!(dt.format_custom("").unwrap(), "");
+
+    assert_eq!(
+        dt.format_custom("%Y-%m-%dT%H:%M:%S.%3fZ").unwrap(),
+        dt.try_to_rfc3339_string().unwrap()
+    );
+    assert_eq!(
+        dt.format_custom("on %d/%m/%Y at %H%M").unwrap(),
+        "on 09/06/2020 at 1058"
+    );
+
+    // times before the epoch and years outside of 0..=9999
+    let dt = crate::DateTime::from_millis(-1);
+    assert_eq!(
+        dt.format_custom("%Y-%m-%d %H:%M:%S.%3f").unwrap(),
+        "1969-12-31 23:59:59.999"
+    );
+    let dt = crate::DateTime::builder()
+        .year(-44)
+        .month(3)
+        .day(15)
+        .build()
+        .unwrap();
+    assert_eq!(dt.format_custom("%Y-%m-%d").unwrap(), "-0044-03-15");
+    assert!(crate::DateTime::MAX.format_custom("%Y").is_ok());
+
+    for pattern in ["%y", "%3", "%3d", "%"] {
+        assert!(dt.format_custom(pattern).is_err(), "{}", pattern);
+    }
+}
+
+#[test]
+fn timestamp_document() {
+    let _guard = LOCK.run_concurrently();
+
+    for dt in [
+        crate::DateTime::now(),
+        crate::DateTime::from_millis(0),
+        crate::DateTime::from_millis(-1),
+        crate::DateTime::MIN,
+        crate::DateTime::MAX,
+    ] {
+        let doc = dt.to_timestamp_document();
+        assert_eq!(
+            doc,
+            crate::doc! { "$date": { "$numberLong": dt.timestamp_millis().to_string() } }
+        );
+        assert_eq!(crate::DateTime::from_timestamp_document(&doc).unwrap(), dt);
+    }
+
+    for doc in [
+        crate::doc! {},
+        crate::doc! { "$date": "2020-06-09T10:58:07.095Z" },
+        crate::doc! { "$date": { "$numberLong": 5i64 } },
+        crate::doc! { "$date": { "$numberLong": "five" } },
+        crate::doc! { "$date": { "$numberLong": "5", "extra": 1 } },
+        crate::doc! { "$date": { "$numberLong": "5" }, "extra": 1 },
+    ] {
+        assert!(
+            crate::DateTime::from_timestamp_document(&doc).is_err(),
+            "{}",
+            doc
+        );
+    }
 }
@@ -1,4 +1,11 @@
-use crate::{base64, spec::BinarySubtype, tests::LOCK, Binary};
+use crate::{
+    base64,
+    binary::EncryptionAlgorithm,
+    error::ErrorKind,
+    spec::BinarySubtype,
+    tests::LOCK,
+    Binary,
+};
 
 #[test]
 fn binary_from_base64() {
@@ -19,3 +26,76 @@ fn binary_from_base64() {
     };
     assert_eq!(produced, expected);
 }
+
+#[test]
+fn encryption_algorithm() {
+    let _guard = LOCK.run_concurrently();
+
+    let encrypted = |bytes: Vec<u8>| Binary {
+        subtype: BinarySubtype::Encrypted,
+        bytes,
+    };
+
+    let deterministic = encrypted(vec![1, 0xAB, 0xCD]);
+    assert!(deterministic.is_encrypted());
+    assert_eq!(
+        deterministic.encryption_algorithm(),
+        Some(EncryptionAlgorithm::Deterministic)
+    );
+
+    let random = encrypted(vec![2, 0xAB, 0xCD]);
+    assert!(random.is_encrypted());
+    assert_eq!(
+        random.encryption_algorithm(),
+        Some(EncryptionAlgorithm::Random)
+    );
+
+    // an intent-to-encrypt marking rather than a ciphertext
+    assert_eq!(encrypted(vec![0, 1]).encryption_algorithm(), None);
+    assert_eq!(encrypted(vec![3]).encryption_algorithm(), None);
+    assert_eq!(encrypted(vec![]).encryption_algorithm(), None);
+    assert!(encrypted(vec![]).is_encrypted());
+
+    let generic = Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: vec![1, 0xAB, 0xCD],
+    };
+    assert!(!generic.is_encrypted());
+    assert_eq!(generic.encryption_algorithm(), None);
+}
+
+#[test]
+fn uuid_str() {
+    let _guard = LOCK.run_concurrently();
+
+    let s = "00112233-4455-6677-8899-aabbccddeeff";
+    let binary = Binary::from_uuid_str(s).unwrap();
+    assert_eq!(
+        binary,
+        Binary {
+            subtype: BinarySubtype::Uuid,
+            bytes: hex::decode("00112233445566778899aabbccddeeff").unwrap(),
+        }
+    );
+    assert_eq!(binary.to_uuid_str().unwrap(), s);
+
+    for invalid in ["", "not a uuid", "00112233-4455-6677-8899-aabbccddee"] {
+        assert!(matches!(
+            Binary::from_uuid_str(invalid).map_err(|e| e.kind),
+            Err(ErrorKind::Uuid { .. })
+        ));
+    }
+
+    for subtype in [BinarySubtype::Generic, BinarySubtype::UuidOld] {
+        let binary = Binary {
+            subtype,
+            ..binary.clone()
+        };
+        assert!(binary.to_uuid_str().is_err());
+    }
+    let short = Binary {
+        subtype: BinarySubtype::Uuid,
+        bytes: vec![0; 15],
+    };
+    assert!(short.to_uuid_str().is_err());
+}
@@ -55,6 +55,50 @@ fn to_json() {
     assert_eq!(alphanumeric.as_str().unwrap(), "bar");
 }
 
+#[test]
+#[cfg(feature = "serde_json-1")]
+fn from_json_str() {
+    let _guard = LOCK.run_concurrently();
+
+    let oid = ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap();
+    assert_eq!(
+        Bson::from_str_json(r#"{ "$oid": "507f1f77bcf86cd799439011" }"#).unwrap(),
+        Bson::ObjectId(oid)
+    );
+
+    let expected_dt = Bson::DateTime(DateTime::from_millis(1_590_972_160_292));
+    assert_eq!(
+        Bson::from_str_json(r#"{ "$date": { "$numberLong": "1590972160292" } }"#).unwrap(),
+        expected_dt
+    );
+    assert_eq!(
+        Bson::from_str_json(r#"{ "$date": "2020-06-01T00:42:40.292Z" }"#).unwrap(),
+        expected_dt
+    );
+
+    assert_eq!(
+        Bson::from_str_json(r#"{ "$binary": { "base64": "AQID", "subType": "00" } }"#).unwrap(),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![1, 2, 3],
+        })
+    );
+
+    assert_eq!(Bson::from_str_json("5").unwrap(), Bson::Int32(5));
+    assert_eq!(
+        Bson::from_str_json(r#"{ "$numberLong": "5" }"#).unwrap(),
+        Bson::Int64(5)
+    );
+
+    let nested = r#"{ "a": { "b": [1, { "$oid": "507f1f77bcf86cd799439011" }] }, "c": "d" }"#;
+    let expected = Bson::Document(doc! { "a": { "b": [1, oid] }, "c": "d" });
+    assert_eq!(Bson::from_str_json(nested).unwrap(), expected);
+    assert_eq!(Bson::from_reader_json(nested.as_bytes()).unwrap(), expected);
+
+    assert!(Bson::from_str_json(r#"{ "$numberLong": 5 }"#).is_err());
+    assert!(Bson::from_str_json("{ not json").is_err());
+}
+
 #[test]
 fn bson_default() {
     let _guard = LOCK.run_concurrently();
@@ -240,6 +284,22 @@ fn timestamp_ordering() {
     assert!(ts2 < ts3);
 }
 
+#[test]
+fn timestamp_arithmetic() {
+    let _guard = LOCK.run_concurrently();
+    let ts = |time, increment| Timestamp { time, increment };
+
+    assert_eq!(ts(1, 2).checked_add(ts(3, 4)), Some(ts(4, 6)));
+    assert_eq!(ts(1, u32::MAX).checked_add(ts(0, 1)), Some(ts(2, 0)));
+    assert_eq!(ts(1, u32::MAX).checked_add(ts(1, 2)), Some(ts(3, 1)));
+    assert_eq!(ts(u32::MAX, 0).checked_add(ts(1, 0)), None);
+    assert_eq!(ts(u32::MAX, u32::MAX).checked_add(ts(0, 1)), None);
+
+    assert_eq!(ts(4, 6).checked_sub(ts(3, 4)), Some(ts(1, 2)));
+    assert_eq!(ts(2, 0).checked_sub(ts(0, 1)), Some(ts(1, u32::MAX)));
+    assert_eq!(ts(0, 0).checked_sub(ts(0, 1)), None);
+}
+
 #[test]
 fn from_external_datetime() {
     use time::macros::datetime;
@@ -507,3 +567,705 @@ fn test_hashable() {
 
     assert!(map.is_empty());
 }
+
+#[test]
+fn test_hash_consistency() {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashSet},
+        hash::{Hash, Hasher},
+    };
+
+    fn hash(bson: &Bson) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        bson.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let _guard = LOCK.run_concurrently();
+
+    assert_eq!(
+        hash(&bson!({ "a": [1, 2.5] })),
+        hash(&bson!({ "a": [1, 2.5] }))
+    );
+    assert_eq!(hash(&Bson::Double(0.0)), hash(&Bson::Double(-0.0)));
+
+    let other_nan = f64::from_bits(f64::NAN.to_bits() | 1);
+    assert!(other_nan.is_nan());
+    assert_eq!(
+        hash(&Bson::Double(f64::NAN)),
+        hash(&Bson::Double(other_nan))
+    );
+    assert_eq!(
+        hash(&Bson::Double(f64::NAN)),
+        hash(&Bson::Double(-f64::NAN))
+    );
+
+    let values = [
+        Bson::Null,
+        Bson::Undefined,
+        Bson::MaxKey,
+        Bson::MinKey,
+        Bson::Int32(1),
+        Bson::Int64(1),
+        Bson::String("a".to_string()),
+        Bson::Symbol("a".to_string()),
+        Bson::JavaScriptCode("a".to_string()),
+    ];
+    let hashes: HashSet<u64> = values.iter().map(hash).collect();
+    assert_eq!(hashes.len(), values.len());
+}
+
+#[test]
+fn type_name() {
+    let _guard = LOCK.run_concurrently();
+
+    let values = vec![
+        (Bson::Double(1.5), "Double"),
+        (Bson::String("x".to_string()), "String"),
+        (Bson::Array(vec![]), "Array"),
+        (Bson::Document(Document::new()), "Document"),
+        (Bson::Boolean(true), "Boolean"),
+        (Bson::Null, "Null"),
+        (
+            Bson::RegularExpression(Regex::from_strings("a", "i").unwrap()),
+            "RegularExpression",
+        ),
+        (Bson::JavaScriptCode("x".to_string()), "JavaScriptCode"),
+        (
+            Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                code: "x".to_string(),
+                scope: Document::new(),
+            }),
+            "JavaScriptCodeWithScope",
+        ),
+        (Bson::Int32(1), "Int32"),
+        (Bson::Int64(1), "Int64"),
+        (
+            Bson::Timestamp(Timestamp {
+                time: 1,
+                increment: 1,
+            }),
+            "Timestamp",
+        ),
+        (
+            Bson::Binary(Binary {
+                subtype: BinarySubtype::Generic,
+                bytes: vec![],
+            }),
+            "Binary",
+        ),
+        (Bson::ObjectId(ObjectId::new()), "ObjectId"),
+        (Bson::DateTime(DateTime::now()), "DateTime"),
+        (Bson::Symbol("x".to_string()), "Symbol"),
+        (
+            Bson::Decimal128(crate::Decimal128::from_bytes([0; 16])),
+            "Decimal128",
+        ),
+        (Bson::Undefined, "Undefined"),
+        (Bson::MaxKey, "MaxKey"),
+        (Bson::MinKey, "MinKey"),
+        (
+            Bson::DbPointer(crate::DbPointer {
+                namespace: "db.coll".to_string(),
+                id: ObjectId::new(),
+            }),
+            "DbPointer",
+        ),
+    ];
+
+    for (value, expected) in values {
+        assert!(!value.type_name().is_empty());
+        assert_eq!(value.type_name(), expected);
+    }
+}
+
+#[test]
+fn unwrap_or_helpers() {
+    let _guard = LOCK.run_concurrently();
+
+    let doc = doc! {
+        "str": "hello",
+        "i32": 1_i32,
+        "i64": 2_i64,
+        "bool": true,
+        "f64": 1.5,
+    };
+    let null = Bson::Null;
+    let value = |key: &str| doc.get(key).unwrap_or(&null);
+
+    // value present with the expected type
+    assert_eq!(value("str").unwrap_str_or("default"), "hello");
+    assert_eq!(value("i32").unwrap_i32_or(-1), 1);
+    assert_eq!(value("i64").unwrap_i64_or(-1), 2);
+    assert!(value("bool").unwrap_bool_or(false));
+    assert_eq!(value("f64").unwrap_f64_or(-1.0), 1.5);
+
+    // value absent
+    assert_eq!(value("missing").unwrap_str_or("default"), "default");
+    assert_eq!(value("missing").unwrap_i32_or(-1), -1);
+    assert_eq!(value("missing").unwrap_i64_or(-1), -1);
+    assert!(!value("missing").unwrap_bool_or(false));
+    assert_eq!(value("missing").unwrap_f64_or(-1.0), -1.0);
+
+    // value present with the wrong type
+    assert_eq!(value("i32").unwrap_str_or("default"), "default");
+    assert_eq!(value("i64").unwrap_i32_or(-1), -1);
+    assert_eq!(value("i32").unwrap_i64_or(-1), -1);
+    assert!(!value("str").unwrap_bool_or(false));
+    assert_eq!(value("i32").unwrap_f64_or(-1.0), -1.0);
+}
+
+#[test]
+fn non_null() {
+    let _guard = LOCK.run_concurrently();
+
+    assert_eq!(Bson::Null.as_non_null(), None);
+    assert_eq!(Bson::Null.into_non_null(), None);
+
+    for value in [
+        Bson::String(String::new()),
+        Bson::Int32(0),
+        Bson::Boolean(false),
+        Bson::Undefined,
+        Bson::Array(vec![Bson::Null]),
+        Bson::Document(doc! { "a": null }),
+    ] {
+        assert_eq!(value.as_non_null(), Some(&value));
+        assert_eq!(value.clone().into_non_null(), Some(value));
+    }
+}
+
+#[test]
+fn as_array_of() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::new();
+
+    assert_eq!(bson!(["a", ""]).as_array_of_str(), Some(vec!["a", ""]));
+    assert_eq!(bson!([1, 2]).as_array_of_i32(), Some(vec![1, 2]));
+    assert_eq!(bson!([1_i64, 2_i64]).as_array_of_i64(), Some(vec![1, 2]));
+    assert_eq!(bson!([1.5, 2.5]).as_array_of_f64(), Some(vec![1.5, 2.5]));
+    assert_eq!(
+        bson!([true, false]).as_array_of_bool(),
+        Some(vec![true, false])
+    );
+    assert_eq!(
+        bson!([{ "a": 1 }, {}]).as_array_of_documents(),
+        Some(vec![&doc! { "a": 1 }, &doc! {}])
+    );
+    assert_eq!(bson!([oid]).as_array_of_object_ids(), Some(vec![oid]));
+    assert_eq!(bson!([]).as_array_of_str(), Some(vec![]));
+
+    // mixed types, including different integer widths
+    let mixed = bson!([1, "two", 3]);
+    assert_eq!(mixed.as_array_of_i32(), None);
+    assert_eq!(mixed.as_array_of_str(), None);
+    assert_eq!(bson!([1, 2_i64]).as_array_of_i64(), None);
+    assert_eq!(bson!([1, 2.5]).as_array_of_f64(), None);
+    assert_eq!(bson!(["a", null]).as_array_of_str(), None);
+
+    // not an array
+    assert_eq!(Bson::String("a".to_string()).as_array_of_str(), None);
+    assert_eq!(bson!({ "0": 1 }).as_array_of_i32(), None);
+
+    // custom conversions
+    assert_eq!(
+        mixed.as_array_of(|value| Some(value.to_string())),
+        Some(vec![
+            "1".to_string(),
+            "\"two\"".to_string(),
+            "3".to_string()
+        ])
+    );
+    assert_eq!(
+        bson!([1, 2_i64]).as_array_of(|value| match value {
+            Bson::Int32(i) => Some(i64::from(*i)),
+            Bson::Int64(i) => Some(*i),
+            _ => None,
+        }),
+        Some(vec![1, 2])
+    );
+}
+
+#[test]
+fn size_in_document() {
+    let _guard = LOCK.run_concurrently();
+
+    let doc = doc! {
+        "double": 2.5,
+        "string": "hello",
+        "array": [1, "two", { "three": 3 }],
+        "document": { "a": 1, "b": { "c": Bson::Null } },
+        "binary": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] },
+        "binary_old": Binary { subtype: BinarySubtype::BinaryOld, bytes: vec![1, 2, 3] },
+        "undefined": Bson::Undefined,
+        "oid": ObjectId::new(),
+        "bool": true,
+        "datetime": DateTime::now(),
+        "null": Bson::Null,
+        "regex": Regex { pattern: cstr!("^a+").into(), options: cstr!("i").into() },
+        "db_pointer": Bson::DbPointer(crate::DbPointer {
+            namespace: "db.coll".to_string(),
+            id: ObjectId::new(),
+        }),
+        "code": Bson::JavaScriptCode("x = 1".to_string()),
+        "code_w_scope": JavaScriptCodeWithScope {
+            code: "x = y".to_string(),
+            scope: doc! { "y": 1 },
+        },
+        "symbol": Bson::Symbol("sym".to_string()),
+        "i32": 1,
+        "timestamp": Timestamp { time: 1, increment: 2 },
+        "i64": 1i64,
+        "decimal": Bson::Decimal128(crate::Decimal128::from_bytes([0; 16])),
+        "min": Bson::MinKey,
+        "max": Bson::MaxKey,
+    };
+    let bytes = doc.encode_to_vec().unwrap();
+
+    for (key, value) in &doc {
+        let element = doc! { key: value.clone() };
+        assert_eq!(
+            value.size_in_document(key) + 5,
+            element.encode_to_vec().unwrap().len(),
+            "{}",
+            key
+        );
+    }
+
+    let elements: usize = doc.iter().map(|(k, v)| v.size_in_document(k)).sum();
+    assert_eq!(elements + 5, bytes.len());
+    assert_eq!(Bson::Document(doc).size_bytes(), bytes.len());
+}
+
+#[test]
+fn approximate_size_bytes() {
+    let _guard = LOCK.run_concurrently();
+
+    let flat = doc! {
+        "double": 1.5,
+        "string": "hello",
+        "binary": Binary { subtype: BinarySubtype::BinaryOld, bytes: vec![1, 2, 3] },
+        "regex": Regex::new("a+", "i").unwrap(),
+        "code": Bson::JavaScriptCode("x".to_string()),
+        "null": null,
+    };
+    for (_, value) in &flat {
+        assert_eq!(
+            value.approximate_size_bytes(),
+            value.size_bytes(),
+            "{:?}",
+            value
+        );
+    }
+
+    // exact without nested documents or arrays
+    let flat_array = Bson::Array((0..12).map(Bson::Int32).collect());
+    let code_with_scope = Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+        code: "code".to_string(),
+        scope: doc! { "a": 1 },
+    });
+    for value in [Bson::Document(flat.clone()), flat_array, code_with_scope] {
+        assert_eq!(
+            value.approximate_size_bytes(),
+            value.size_bytes(),
+            "{:?}",
+            value
+        );
+    }
+
+    // a lower bound otherwise
+    for value in [
+        bson!({ "a": { "b": [1, 2, { "c": "d" }] } }),
+        bson!([[1], { "a": 2 }]),
+        Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: "code".to_string(),
+            scope: doc! { "a": { "b": 1 } },
+        }),
+    ] {
+        assert!(value.approximate_size_bytes() < value.size_bytes());
+    }
+    assert_eq!(
+        bson!({ "a": { "b": 1 } }).approximate_size_bytes(),
+        bson!({ "a": {} }).size_bytes()
+    );
+}
+
+#[test]
+fn regex_new() {
+    let _guard = LOCK.run_concurrently();
+
+    let regex = Regex::new("^a.*b$", "imsx").unwrap();
+    assert_eq!(regex.pattern.as_str(), "^a.*b$");
+    assert_eq!(regex.options.as_str(), "imsx");
+    assert_eq!(Regex::new("", "").unwrap().options.as_str(), "");
+
+    // options are sorted and deduplicated
+    assert_eq!(Regex::new("a", "xumi").unwrap().options.as_str(), "imux");
+    assert_eq!(Regex::new("a", "iii").unwrap().options.as_str(), "i");
+
+    let error = Regex::new("a", "ig").unwrap_err();
+    assert!(matches!(error.kind, crate::error::ErrorKind::Regex { .. }));
+    assert!(error.to_string().contains("'g'"), "{}", error);
+
+    assert!(Regex::new("a\0b", "i").is_err());
+    assert!(Regex::new("a", "i\0").is_err());
+}
+
+#[test]
+fn sort_keys() {
+    let _guard = LOCK.run_concurrently();
+
+    let unsorted = doc! {
+        "b": 1,
+        "a": {
+            "z": [{ "y": 1, "x": 2 }, 3],
+            "c": "d",
+        },
+        "code": JavaScriptCodeWithScope {
+            code: "x".to_string(),
+            scope: doc! { "n": 1, "m": 2 },
+        },
+    };
+    let sorted = doc! {
+        "a": {
+            "c": "d",
+            "z": [{ "x": 2, "y": 1 }, 3],
+        },
+        "b": 1,
+        "code": JavaScriptCodeWithScope {
+            code: "x".to_string(),
+            scope: doc! { "m": 2, "n": 1 },
+        },
+    };
+    assert_ne!(
+        unsorted.encode_to_vec().unwrap(),
+        sorted.encode_to_vec().unwrap()
+    );
+
+    let result = match Bson::Document(unsorted.clone()).sort_keys() {
+        Bson::Document(doc) => doc,
+        other => panic!("expected document, got {:?}", other),
+    };
+    assert_eq!(result, unsorted);
+    assert_eq!(
+        result.encode_to_vec().unwrap(),
+        sorted.encode_to_vec().unwrap()
+    );
+
+    // sorting is idempotent, and leaves other values untouched
+    assert_eq!(
+        Bson::Document(sorted.clone()).sort_keys(),
+        Bson::Document(sorted)
+    );
+    assert_eq!(Bson::Int32(5).sort_keys(), Bson::Int32(5));
+}
+
+#[test]
+fn replace_all() {
+    let _guard = LOCK.run_concurrently();
+
+    let value = bson!({
+        "a": "x",
+        "b": null,
+        "c": [1, "y", [null, "z"]],
+        "d": { "e": "w", "f": { "g": null } },
+        "h": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: "code".to_string(),
+            scope: doc! { "i": "v" },
+        }),
+    });
+
+    let is_string = |value: &Bson| matches!(value, Bson::String(_));
+    assert_eq!(
+        value
+            .clone()
+            .replace_all(is_string, Bson::String(String::new())),
+        bson!({
+            "a": "",
+            "b": null,
+            "c": [1, "", [null, ""]],
+            "d": { "e": "", "f": { "g": null } },
+            "h": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                code: "code".to_string(),
+                scope: doc! { "i": "" },
+            }),
+        })
+    );
+
+    assert_eq!(
+        value
+            .clone()
+            .replace_all(|value| value == &Bson::Null, Bson::Int32(0)),
+        bson!({
+            "a": "x",
+            "b": 0,
+            "c": [1, "y", [0, "z"]],
+            "d": { "e": "w", "f": { "g": 0 } },
+            "h": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                code: "code".to_string(),
+                scope: doc! { "i": "v" },
+            }),
+        })
+    );
+
+    // matching containers are replaced without visiting their contents, and replacements are
+    // not tested
+    let is_document = |value: &Bson| matches!(value, Bson::Document(_));
+    assert_eq!(
+        bson!({ "a": { "b": 1 } }).replace_all(is_document, bson!({ "c": 2 })),
+        bson!({ "c": 2 })
+    );
+    assert_eq!(
+        bson!([{ "a": { "b": 1 } }, 2]).replace_all(is_document, bson!({ "c": 2 })),
+        bson!([{ "c": 2 }, 2])
+    );
+    assert_eq!(
+        Bson::Int32(5).replace_all(|_| false, Bson::Null),
+        Bson::Int32(5)
+    );
+}
+
+#[test]
+fn visit() {
+    let _guard = LOCK.run_concurrently();
+
+    let mut value = bson!({
+        "a": "x",
+        "b": [1, "y", [2]],
+        "c": { "d": 3, "e": {} },
+        "f": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: "code".to_string(),
+            scope: doc! { "g": 4 },
+        }),
+    });
+
+    let mut count = 0;
+    value.visit(&mut |_| count += 1);
+    assert_eq!(count, 12);
+
+    let mut visited = Vec::new();
+    value.visit(&mut |value| match value {
+        Bson::String(s) => visited.push(s.clone()),
+        Bson::Int32(i) => visited.push(i.to_string()),
+        _ => {}
+    });
+    assert_eq!(visited, ["x", "1", "y", "2", "3", "4"]);
+
+    value.visit_mut(&mut |value| {
+        if let Bson::Int32(i) = value {
+            *i *= 2;
+        }
+    });
+    assert_eq!(
+        value,
+        bson!({
+            "a": "x",
+            "b": [2, "y", [4]],
+            "c": { "d": 6, "e": {} },
+            "f": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                code: "code".to_string(),
+                scope: doc! { "g": 8 },
+            }),
+        })
+    );
+
+    // values added by the visitor are visited in turn
+    let mut value = bson!([1]);
+    value.visit_mut(&mut |value| {
+        if let Bson::Int32(i) = value {
+            if *i < 3 {
+                *value = bson!([*i + 1]);
+            }
+        }
+    });
+    assert_eq!(value, bson!([[[3]]]));
+}
+
+#[test]
+fn normalize_ints() {
+    let _guard = LOCK.run_concurrently();
+
+    assert_eq!(Bson::Int32(5).normalize_to_int64(), Bson::Int64(5));
+    assert_eq!(Bson::Int64(5).normalize_to_smallest_int(), Bson::Int32(5));
+    for i in [
+        i64::from(i32::MAX) + 1,
+        i64::from(i32::MIN) - 1,
+        i64::MAX,
+        i64::MIN,
+    ] {
+        assert_eq!(Bson::Int64(i).normalize_to_smallest_int(), Bson::Int64(i));
+    }
+    assert_eq!(
+        Bson::Int64(i32::MIN.into()).normalize_to_smallest_int(),
+        Bson::Int32(i32::MIN)
+    );
+
+    let value = bson!({
+        "a": 1,
+        "b": [2_i64, { "c": 3 }, 4_000_000_000_i64],
+        "d": 1.5,
+        "e": "5",
+        "f": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+            code: "x".to_string(),
+            scope: doc! { "g": 6 },
+        }),
+    });
+    let wide = value.clone().normalize_to_int64();
+    assert_eq!(
+        wide,
+        bson!({
+            "a": 1_i64,
+            "b": [2_i64, { "c": 3_i64 }, 4_000_000_000_i64],
+            "d": 1.5,
+            "e": "5",
+            "f": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                code: "x".to_string(),
+                scope: doc! { "g": 6_i64 },
+            }),
+        })
+    );
+    assert_eq!(
+        wide.normalize_to_smallest_int(),
+        bson!({
+            "a": 1,
+            "b": [2, { "c": 3 }, 4_000_000_000_i64],
+            "d": 1.5,
+            "e": "5",
+            "f": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+                code: "x".to_string(),
+                scope: doc! { "g": 6 },
+            }),
+        })
+    );
+}
+
+#[test]
+fn flatten_to_document() {
+    let _guard = LOCK.run_concurrently();
+
+    let flat = bson!({ "a": { "b": 1, "c": "x" }, "d": true })
+        .flatten_to_document(".")
+        .unwrap();
+    assert_eq!(flat, doc! { "a.b": 1, "a.c": "x", "d": true });
+    assert!(flat.keys().eq(["a.b", "a.c", "d"]));
+
+    assert_eq!(
+        bson!({ "a": { "b": { "c": [1, [2]] } }, "e": {} })
+            .flatten_to_document("__")
+            .unwrap(),
+        doc! { "a__b__c": [1, [2]], "e": {} }
+    );
+    assert_eq!(
+        Bson::Document(doc! {}).flatten_to_document(".").unwrap(),
+        doc! {}
+    );
+    assert_eq!(
+        bson!({ "a.b": 1, "a": { "b": 2 } })
+            .flatten_to_document(".")
+            .unwrap(),
+        doc! { "a.b": 2 }
+    );
+
+    for value in [
+        bson!({ "a": { "b": [{ "c": 1 }] } }),
+        bson!({ "a": [1, [{}]] }),
+    ] {
+        let error = value.flatten_to_document(".").unwrap_err();
+        assert!(matches!(
+            error.kind,
+            crate::error::ErrorKind::ValueAccess { .. }
+        ));
+    }
+    let error = bson!({ "a": { "b": [{ "c": 1 }] } })
+        .flatten_to_document(".")
+        .unwrap_err();
+    assert_eq!(error.key.as_deref(), Some("a.b"));
+    Bson::Int32(1).flatten_to_document(".").unwrap_err();
+    bson!([{ "a": 1 }]).flatten_to_document(".").unwrap_err();
+}
+
+#[test]
+fn to_bson_trait() {
+    use crate::ToBson;
+
+    let _guard = LOCK.run_concurrently();
+
+    assert_eq!(5i32.to_bson(), Bson::Int32(5));
+    assert_eq!(5i64.to_bson(), Bson::Int64(5));
+    assert_eq!(2.5f64.to_bson(), Bson::Double(2.5));
+    assert_eq!(true.to_bson(), Bson::Boolean(true));
+    assert_eq!("a".to_string().to_bson(), Bson::String("a".to_string()));
+    assert_eq!("a".to_bson(), Bson::String("a".to_string()));
+    let s: &str = "b";
+    assert_eq!(s.to_bson(), Bson::String("b".to_string()));
+
+    let oid = ObjectId::new();
+    assert_eq!(oid.to_bson(), Bson::ObjectId(oid));
+    let dt = DateTime::now();
+    assert_eq!(dt.to_bson(), Bson::DateTime(dt));
+    let binary = Binary {
+        subtype: BinarySubtype::Generic,
+        bytes: vec![1, 2],
+    };
+    assert_eq!(binary.to_bson(), Bson::Binary(binary.clone()));
+    let ts = Timestamp {
+        time: 1,
+        increment: 2,
+    };
+    assert_eq!(ts.to_bson(), Bson::Timestamp(ts));
+    assert_eq!(doc! { "a": 1 }.to_bson(), Bson::Document(doc! { "a": 1 }));
+    assert_eq!(Some(1).to_bson(), Bson::Int32(1));
+    assert_eq!(None::<i32>.to_bson(), Bson::Null);
+
+    let bytes: &[u8] = &[1, 2];
+    assert_eq!(
+        bytes.to_bson(),
+        Bson::Binary(Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![1, 2],
+        })
+    );
+    assert_eq!(
+        ["x", "y"][..].to_bson(),
+        Bson::Array(vec![Bson::from("x"), Bson::from("y")])
+    );
+    assert_eq!(vec![1i64].to_bson(), Bson::Array(vec![Bson::Int64(1)]));
+}
+
+#[test]
+fn canonicalize() {
+    let _guard = LOCK.run_concurrently();
+
+    let oid = ObjectId::parse_str("507f1f77bcf86cd799439011").unwrap();
+    let wrapped = Bson::from(doc! { "$oid": "507f1f77bcf86cd799439011" });
+    assert_eq!(wrapped.canonicalize(), Bson::ObjectId(oid));
+
+    let doc = doc! {
+        "_id": { "$oid": "507f1f77bcf86cd799439011" },
+        "date": { "$date": { "$numberLong": "1590972160292" } },
+        "list": [{ "$numberInt": "1" }, { "$numberDouble": "Infinity" }],
+        "nested": { "bin": { "$binary": { "base64": "AQID", "subType": "00" } } },
+        "plain": { "a": 1, "b": "two" },
+        "native": 5i64,
+    };
+    let expected = doc! {
+        "_id": oid,
+        "date": DateTime::from_millis(1_590_972_160_292),
+        "list": [1, f64::INFINITY],
+        "nested": { "bin": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] } },
+        "plain": { "a": 1, "b": "two" },
+        "native": 5i64,
+    };
+    assert_eq!(Bson::from(doc).canonicalize(), Bson::from(expected));
+
+    // documents that aren't valid wrappers are left alone
+    for doc in [
+        doc! {},
+        doc! { "$oid": "not an oid" },
+        doc! { "$oid": "507f1f77bcf86cd799439011", "other": 1 },
+        doc! { "$set": { "a": 1 } },
+    ] {
+        assert_eq!(Bson::from(doc.clone()).canonicalize(), Bson::from(doc));
+    }
+    assert_eq!(Bson::Int32(1).canonicalize(), Bson::Int32(1));
+}
@@ -1,7 +1,8 @@
 use crate::{
     doc,
+    error::ErrorKind,
     oid::ObjectId,
-    spec::BinarySubtype,
+    spec::{BinarySubtype, ElementType},
     tests::LOCK,
     Binary,
     Bson,
@@ -146,6 +147,68 @@ fn test_getters() {
     assert_eq!(&binary, doc.get_binary_generic("binary").unwrap());
 }
 
+#[test]
+fn get_array_of() {
+    let _guard = LOCK.run_concurrently();
+    let oid = ObjectId::new();
+    let doc = doc! {
+        "strings": ["a", "", "c"],
+        "i32": [1, 2],
+        "i64": [1i64, 2i64],
+        "object_ids": [oid, oid],
+        "empty": [],
+        "mixed": [1, "two", 3],
+        "not_array": "a",
+    };
+
+    assert_eq!(doc.get_array_of_strings("strings").unwrap(), ["a", "", "c"]);
+    assert_eq!(doc.get_array_of_i32("i32").unwrap(), [1, 2]);
+    assert_eq!(doc.get_array_of_i64("i64").unwrap(), [1, 2]);
+    assert_eq!(
+        doc.get_array_of_object_ids("object_ids").unwrap(),
+        [oid, oid]
+    );
+    assert!(doc.get_array_of_strings("empty").unwrap().is_empty());
+
+    // the first element with the wrong type is reported
+    let error = doc.get_array_of_i32("mixed").unwrap_err();
+    assert!(error.is_value_access_unexpected_type());
+    assert_eq!(error.key.as_deref(), Some("mixed"));
+    assert_eq!(error.index, Some(1));
+    let error = doc.get_array_of_strings("mixed").unwrap_err();
+    assert_eq!(error.index, Some(0));
+    // integer widths are not converted
+    assert_eq!(doc.get_array_of_i64("i32").unwrap_err().index, Some(0));
+
+    let error = doc.get_array_of_strings("missing").unwrap_err();
+    assert!(error.is_value_access_not_present());
+    assert_eq!(error.key.as_deref(), Some("missing"));
+    let error = doc.get_array_of_strings("not_array").unwrap_err();
+    assert!(error.is_value_access_unexpected_type());
+    assert_eq!(error.index, None);
+}
+
+#[test]
+fn eq_ignores_key_order() {
+    let _guard = LOCK.run_concurrently();
+
+    assert_eq!(doc! { "a": 1, "b": 2 }, doc! { "b": 2, "a": 1 });
+    assert_eq!(
+        doc! { "a": { "x": 1, "y": [{ "p": 1, "q": 2 }] }, "b": null },
+        doc! { "b": null, "a": { "y": [{ "q": 2, "p": 1 }], "x": 1 } }
+    );
+
+    // arrays are ordered
+    assert_ne!(doc! { "a": [1, 2] }, doc! { "a": [2, 1] });
+    assert_ne!(
+        doc! { "a": [{ "x": 1 }, { "y": 2 }] },
+        doc! { "a": [{ "y": 2 }, { "x": 1 }] }
+    );
+
+    assert_ne!(doc! { "a": 1 }, doc! { "a": 1, "b": 2 });
+    assert_ne!(doc! { "a": 1 }, doc! { "a": 1_i64 });
+}
+
 #[test]
 fn remove() {
     let _guard = LOCK.run_concurrently();
@@ -179,6 +242,377 @@ fn remove() {
     assert_eq!(keys, expected_keys);
 }
 
+#[test]
+fn decimal128_accessors() {
+    let _guard = LOCK.run_concurrently();
+    let decimal: crate::Decimal128 = "3.14".parse().unwrap();
+    let mut doc = doc! { "decimal": decimal, "int": 1 };
+
+    assert_eq!(doc.get_decimal128("decimal").unwrap(), &decimal);
+    assert_eq!(doc.get("decimal").unwrap().as_decimal128(), Some(&decimal));
+    assert!(doc
+        .get_decimal128("int")
+        .unwrap_err()
+        .is_value_access_unexpected_type());
+    assert_eq!(doc.get("int").unwrap().as_decimal128(), None);
+    assert!(doc
+        .get_decimal128("missing")
+        .unwrap_err()
+        .is_value_access_not_present());
+
+    let other: crate::Decimal128 = "-1".parse().unwrap();
+    *doc.get_decimal128_mut("decimal").unwrap() = other;
+    assert_eq!(doc.get_decimal128("decimal").unwrap(), &other);
+    *doc.get_mut("decimal").unwrap().as_decimal128_mut().unwrap() = decimal;
+    assert_eq!(doc.get_decimal128("decimal").unwrap(), &decimal);
+    assert!(doc.get_mut("int").unwrap().as_decimal128_mut().is_none());
+}
+
+#[test]
+fn get_nested() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! {
+        "a": { "b": { "c": 1 } },
+        "x": 5,
+        "arr": [{ "d": 1 }],
+    };
+
+    assert_eq!(doc.get_nested(&["a", "b", "c"]), Some(&Bson::Int32(1)));
+    assert_eq!(
+        doc.get_nested(&["a", "b"]),
+        Some(&Bson::Document(doc! { "c": 1 }))
+    );
+    assert_eq!(doc.get_nested(&["x"]), Some(&Bson::Int32(5)));
+    assert_eq!(doc.get_nested(&[]), None);
+
+    // missing intermediate
+    assert_eq!(doc.get_nested(&["a", "missing", "c"]), None);
+    assert_eq!(doc.get_nested(&["a", "b", "missing"]), None);
+
+    // non-document intermediate
+    assert_eq!(doc.get_nested(&["x", "c"]), None);
+    assert_eq!(doc.get_nested(&["arr", "0", "d"]), None);
+
+    *doc.get_nested_mut(&["a", "b", "c"]).unwrap() = Bson::Int32(2);
+    assert_eq!(doc.get_nested(&["a", "b", "c"]), Some(&Bson::Int32(2)));
+    assert!(doc.get_nested_mut(&["a", "missing", "c"]).is_none());
+    assert!(doc.get_nested_mut(&["x", "c"]).is_none());
+}
+
+#[test]
+fn get_path_or_insert_with() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "name": "x" };
+
+    let value = doc
+        .get_path_or_insert_with("a.b.c", || Bson::Int32(1))
+        .unwrap();
+    assert_eq!(value, &Bson::Int32(1));
+    *value = Bson::Int32(2);
+    assert_eq!(doc, doc! { "name": "x", "a": { "b": { "c": 2 } } });
+
+    // existing values are returned without calling `f`
+    let value = doc
+        .get_path_or_insert_with("a.b.c", || panic!("should not be called"))
+        .unwrap();
+    assert_eq!(value, &Bson::Int32(2));
+    doc.get_path_or_insert_with("a.b", || panic!("should not be called"))
+        .unwrap();
+
+    // existing documents along the path are reused
+    doc.get_path_or_insert_with("a.d", || Bson::Boolean(true))
+        .unwrap();
+    doc.get_path_or_insert_with("e", || Bson::Null).unwrap();
+    assert_eq!(
+        doc,
+        doc! { "name": "x", "a": { "b": { "c": 2 }, "d": true }, "e": null }
+    );
+
+    // paths through non-document values
+    let before = doc.clone();
+    let error = doc
+        .get_path_or_insert_with("a.b.c.d", || Bson::Null)
+        .unwrap_err();
+    assert!(error.is_value_access_unexpected_type());
+    assert_eq!(error.key.as_deref(), Some("a.b.c"));
+    let error = doc
+        .get_path_or_insert_with("name.first", || Bson::Null)
+        .unwrap_err();
+    assert_eq!(error.key.as_deref(), Some("name"));
+    assert_eq!(doc, before);
+}
+
+#[test]
+fn apply_projection() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "_id": 1,
+        "name": "x",
+        "address": { "city": "y", "zip": "z", "geo": { "lat": 1.5, "lng": 2.5 } },
+        "tags": ["a", "b"],
+    };
+
+    assert_eq!(doc.apply_projection(&["name"], true), doc! { "name": "x" });
+    assert_eq!(
+        doc.apply_projection(&["name"], false),
+        doc! {
+            "_id": 1,
+            "address": { "city": "y", "zip": "z", "geo": { "lat": 1.5, "lng": 2.5 } },
+            "tags": ["a", "b"],
+        }
+    );
+
+    // field order follows the document rather than the list of fields
+    let projected = doc.apply_projection(&["address.geo.lat", "address.city", "_id"], true);
+    assert_eq!(
+        projected,
+        doc! { "_id": 1, "address": { "city": "y", "geo": { "lat": 1.5 } } }
+    );
+    assert_eq!(projected.keys().collect::<Vec<_>>(), ["_id", "address"]);
+    assert_eq!(
+        doc.apply_projection(&["address.geo", "address.zip", "tags"], false),
+        doc! { "_id": 1, "name": "x", "address": { "city": "y" } }
+    );
+
+    // the whole field takes precedence over nested paths within it
+    assert_eq!(
+        doc.apply_projection(&["address.city", "address"], true),
+        doc! { "address": { "city": "y", "zip": "z", "geo": { "lat": 1.5, "lng": 2.5 } } }
+    );
+
+    // paths through missing fields or non-document values
+    assert_eq!(doc.apply_projection(&["missing", "name.x"], true), doc! {});
+    assert_eq!(doc.apply_projection(&["missing", "name.x"], false), doc);
+
+    assert_eq!(doc.apply_projection(&[], true), doc! {});
+    assert_eq!(doc.apply_projection(&[], false), doc);
+}
+
+#[test]
+fn select_fields() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "a": 1, "b": "two", "c": { "d": 3 }, "c.d": 4 };
+
+    let selected = doc.select_fields(["c", "a"]);
+    assert_eq!(selected, doc! { "c": { "d": 3 }, "a": 1 });
+    // field order follows the list of keys rather than the document
+    assert_eq!(selected.keys().collect::<Vec<_>>(), ["c", "a"]);
+
+    // missing keys are skipped, and keys are not treated as paths
+    assert_eq!(doc.select_fields(["missing", "b"]), doc! { "b": "two" });
+    assert_eq!(doc.select_fields(["c.d"]), doc! { "c.d": 4 });
+
+    // duplicate keys are only included once, at their first position
+    let selected = doc.select_fields(["b", "a", "b", "a"]);
+    assert_eq!(selected.keys().collect::<Vec<_>>(), ["b", "a"]);
+
+    assert_eq!(doc.select_fields([]), doc! {});
+    let keys = ["a".to_string()];
+    assert_eq!(
+        doc.select_fields(keys.iter().map(String::as_str)),
+        doc! { "a": 1 }
+    );
+}
+
+#[test]
+fn transform_field() {
+    let _guard = LOCK.run_concurrently();
+    let mut doc = doc! { "a": 1, "b": "two", "c": 3 };
+
+    let applied = doc
+        .transform_field("b", |value| match value {
+            Bson::String(s) => Ok(Bson::Int32(s.len() as i32)),
+            other => Ok(other),
+        })
+        .unwrap();
+    assert!(applied);
+    assert_eq!(doc, doc! { "a": 1, "b": 3, "c": 3 });
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+    let mut called = false;
+    let applied = doc
+        .transform_field("missing", |value| {
+            called = true;
+            Ok(value)
+        })
+        .unwrap();
+    assert!(!applied);
+    assert!(!called);
+    assert_eq!(doc, doc! { "a": 1, "b": 3, "c": 3 });
+
+    let error = doc
+        .transform_field("a", |value| {
+            let mut inner = Document::try_from(value)?;
+            inner.insert("x", 1);
+            Ok(inner.into())
+        })
+        .unwrap_err();
+    assert!(matches!(error.kind, ErrorKind::ValueAccess { .. }));
+    // a failed transformation leaves the document unchanged
+    assert_eq!(doc, doc! { "a": 1, "b": 3, "c": 3 });
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+}
+
+#[test]
+fn zip() {
+    let _guard = LOCK.run_concurrently();
+    let zipped = |a: &Document, b: &Document| {
+        Document::zip(a, b)
+            .map(|(key, a, b)| (key.to_string(), a.cloned(), b.cloned()))
+            .collect::<Vec<_>>()
+    };
+
+    let a = doc! { "x": 1, "y": "two" };
+    let b = doc! { "y": "three", "x": 4 };
+    assert_eq!(
+        zipped(&a, &b),
+        [
+            ("x".to_string(), Some(Bson::Int32(1)), Some(Bson::Int32(4))),
+            (
+                "y".to_string(),
+                Some(Bson::String("two".to_string())),
+                Some(Bson::String("three".to_string()))
+            ),
+        ]
+    );
+
+    let c = doc! { "z": null, "w": 5 };
+    assert_eq!(
+        zipped(&a, &c),
+        [
+            ("x".to_string(), Some(Bson::Int32(1)), None),
+            ("y".to_string(), Some(Bson::String("two".to_string())), None),
+            ("z".to_string(), None, Some(Bson::Null)),
+            ("w".to_string(), None, Some(Bson::Int32(5))),
+        ]
+    );
+
+    let empty = Document::new();
+    assert_eq!(
+        zipped(&empty, &c),
+        [
+            ("z".to_string(), None, Some(Bson::Null)),
+            ("w".to_string(), None, Some(Bson::Int32(5))),
+        ]
+    );
+    assert_eq!(
+        zipped(&c, &empty),
+        [
+            ("z".to_string(), Some(Bson::Null), None),
+            ("w".to_string(), Some(Bson::Int32(5)), None),
+        ]
+    );
+    assert!(zipped(&empty, &empty).is_empty());
+}
+
+#[test]
+fn diff() {
+    let _guard = LOCK.run_concurrently();
+
+    let old = doc! {
+        "name": "x",
+        "count": 1,
+        "nested": { "a": 1, "b": 2 },
+    };
+
+    assert_eq!(old.diff(&old), doc! {});
+    assert_eq!(Document::new().diff(&Document::new()), doc! {});
+
+    let mut new = old.clone();
+    new.insert("added", true);
+    assert_eq!(old.diff(&new), doc! { "$set": { "added": true } });
+
+    let mut new = old.clone();
+    new.remove("count");
+    assert_eq!(old.diff(&new), doc! { "$unset": { "count": "" } });
+
+    let mut new = old.clone();
+    new.insert("count", 2);
+    // changing the type of a value counts as a change
+    new.insert("name", Bson::Symbol("x".to_string()));
+    assert_eq!(
+        old.diff(&new),
+        doc! { "$set": { "name": Bson::Symbol("x".to_string()), "count": 2 } }
+    );
+
+    // nested documents are compared as a whole
+    let mut new = old.clone();
+    new.insert("nested", doc! { "a": 1, "b": 3 });
+    assert_eq!(
+        old.diff(&new),
+        doc! { "$set": { "nested": { "a": 1, "b": 3 } } }
+    );
+
+    let new = doc! { "count": 5, "other": Bson::Null };
+    assert_eq!(
+        old.diff(&new),
+        doc! {
+            "$set": { "count": 5, "other": Bson::Null },
+            "$unset": { "name": "", "nested": "" },
+        }
+    );
+}
+
+#[test]
+fn map_values() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "z": "abc", "a": 1, "m": { "n": "x" }, "b": 2.5 };
+
+    let identity = doc.clone().map_values(|_, v| v);
+    assert_eq!(identity, doc);
+    assert_eq!(
+        identity.keys().collect::<Vec<_>>(),
+        doc.keys().collect::<Vec<_>>()
+    );
+
+    let uppercase = |_: &str, v: Bson| match v {
+        Bson::String(s) => Bson::String(s.to_uppercase()),
+        other => other,
+    };
+    let upper = doc.clone().map_values(uppercase);
+    assert_eq!(
+        upper,
+        doc! { "z": "ABC", "a": 1, "m": { "n": "x" }, "b": 2.5 }
+    );
+    assert_eq!(upper.keys().collect::<Vec<_>>(), ["z", "a", "m", "b"]);
+
+    let mut widened = doc.clone();
+    widened.map_values_in_place(|_, v| {
+        if let Bson::Int32(i) = *v {
+            *v = Bson::Int64(i.into());
+        }
+    });
+    assert_eq!(
+        widened,
+        doc! { "z": "abc", "a": 1_i64, "m": { "n": "x" }, "b": 2.5 }
+    );
+
+    let mut keyed = doc! { "a": 1, "b": 2 };
+    keyed.map_values_in_place(|k, v| *v = Bson::String(format!("{}={}", k, v)));
+    assert_eq!(keyed, doc! { "a": "a=1", "b": "b=2" });
+    assert_eq!(keyed.keys().collect::<Vec<_>>(), ["a", "b"]);
+
+    let mut noop = doc.clone();
+    noop.map_values_in_place(|_, _| {});
+    assert_eq!(noop, doc);
+    assert_eq!(Document::new().map_values(uppercase), Document::new());
+}
+
+#[test]
+fn with_capacity() {
+    let _guard = LOCK.run_concurrently();
+    let mut without_capacity = Document::new();
+    let mut with_capacity = Document::with_capacity(1000);
+    assert!(with_capacity.is_empty());
+    for i in 0..1000 {
+        without_capacity.insert(format!("key{}", i), i);
+        with_capacity.insert(format!("key{}", i), i);
+    }
+    assert_eq!(with_capacity.len(), 1000);
+    assert_eq!(with_capacity, without_capacity);
+    assert!(with_capacity.keys().eq(without_capacity.keys()));
+}
+
 #[test]
 fn entry() {
     let _guard = LOCK.run_concurrently();
@@ -248,6 +682,318 @@ fn extend() {
     );
 }
 
+#[test]
+fn extend_from_pairs() {
+    let _guard = LOCK.run_concurrently();
+
+    let mut doc = Document::new();
+    doc.extend(vec![("a", Bson::Int32(1)), ("b", Bson::Int32(2))]);
+    assert_eq!(doc, doc! { "a": 1, "b": 2 });
+
+    // later values win, but keep the position of the original key
+    doc.extend([
+        ("a", Bson::Int32(3)),
+        ("a", Bson::Int32(4)),
+        ("c", Bson::Null),
+    ]);
+    assert_eq!(doc, doc! { "a": 4, "b": 2, "c": Bson::Null });
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+    let mut map = std::collections::HashMap::new();
+    map.insert("d".to_string(), Bson::from("x"));
+    map.insert("b".to_string(), Bson::from(true));
+    doc.extend(map);
+    assert_eq!(doc, doc! { "a": 4, "b": true, "c": Bson::Null, "d": "x" });
+
+    let mut empty = Document::new();
+    empty.extend(doc.clone());
+    assert_eq!(empty, doc);
+}
+
+#[test]
+fn values() {
+    let _guard = LOCK.run_concurrently();
+
+    let mut doc = Document::new();
+    assert_eq!(doc.values().count(), 0);
+    assert_eq!(doc.values_mut().count(), 0);
+
+    doc.insert("a", 1);
+    assert_eq!(doc.values().collect::<Vec<_>>(), [&Bson::Int32(1)]);
+
+    doc.insert("b", Bson::Null);
+    doc.insert("c", "x");
+    doc.insert("d", Bson::Null);
+    assert_eq!(doc.values().filter(|v| *v == &Bson::Null).count(), 2);
+
+    for value in doc.values_mut() {
+        if let Bson::Int32(i) = value {
+            *i += 1;
+        } else if value == &Bson::Null {
+            *value = Bson::Boolean(false);
+        }
+    }
+    assert_eq!(doc, doc! { "a": 2, "b": false, "c": "x", "d": false });
+}
+
+#[test]
+fn collect() {
+    let _guard = LOCK.run_concurrently();
+
+    let empty: Document = std::iter::empty::<(String, Bson)>().collect();
+    assert!(empty.is_empty());
+    let empty: Document = Vec::<(&str, Bson)>::new().into_iter().collect();
+    assert!(empty.is_empty());
+
+    let names = ["a", "b", "c"];
+    let doc: Document = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (*name, Bson::from(i as i32)))
+        .collect();
+    assert_eq!(doc, doc! { "a": 0, "b": 1, "c": 2 });
+    assert_eq!(doc.keys().collect::<Vec<_>>(), names);
+
+    let doc: Document = vec![
+        ("x".to_string(), Bson::Null),
+        ("x".to_string(), Bson::from(1)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(doc, doc! { "x": 1 });
+
+    let mut map = std::collections::BTreeMap::new();
+    map.insert("k1".to_string(), Bson::from("v1"));
+    map.insert("k2".to_string(), Bson::from("v2"));
+    let doc: Document = map.into_iter().collect();
+    assert_eq!(doc, doc! { "k1": "v1", "k2": "v2" });
+}
+
+#[test]
+fn size_introspection() {
+    let _guard = LOCK.run_concurrently();
+
+    let empty = Document::new();
+    assert_eq!(empty.longest_key_length(), 0);
+    assert_eq!(empty.total_keys_size(), 0);
+    assert_eq!(empty.shortest_value_size(), 0);
+
+    let uniform = doc! { "a": 1i64, "bb": 2i64, "ccc": 3i64 };
+    assert_eq!(uniform.longest_key_length(), 3);
+    assert_eq!(uniform.total_keys_size(), 6);
+    assert_eq!(uniform.shortest_value_size(), 8);
+
+    let mixed = doc! {
+        "name": "hello",
+        "flag": true,
+        "nested": { "x": 1 },
+        "é": Bson::Null,
+    };
+    // key lengths are measured in bytes
+    assert_eq!(mixed.longest_key_length(), 6);
+    assert_eq!(mixed.total_keys_size(), 16);
+    assert_eq!(mixed.shortest_value_size(), 0);
+
+    let mut mixed = mixed;
+    mixed.remove("é");
+    assert_eq!(mixed.shortest_value_size(), 1);
+}
+
+#[test]
+fn count_type() {
+    let _guard = LOCK.run_concurrently();
+
+    assert_eq!(Document::new().count_type(ElementType::String), 0);
+
+    let doc = doc! {
+        "a": "x",
+        "b": 1,
+        "c": "y",
+        "d": { "e": "nested strings are not counted" },
+        "f": Bson::Null,
+        "g": 2,
+        "h": "z",
+    };
+    assert_eq!(doc.count_type(ElementType::String), 3);
+    assert_eq!(doc.count_type(ElementType::Int32), 2);
+    assert_eq!(doc.count_type(ElementType::EmbeddedDocument), 1);
+    assert_eq!(doc.count_type(ElementType::Null), 1);
+    assert_eq!(doc.count_type(ElementType::Int64), 0);
+}
+
+#[test]
+fn find() {
+    let _guard = LOCK.run_concurrently();
+
+    let oid = ObjectId::new();
+    let doc = doc! {
+        "name": "x",
+        "count": 1,
+        "nested": { "id": ObjectId::new() },
+        "id": oid,
+        "other": 2,
+    };
+
+    assert_eq!(
+        doc.find(|k, _| k == "count"),
+        Some(("count", &Bson::Int32(1)))
+    );
+    assert_eq!(doc.find(|k, _| k == "missing"), None);
+    assert_eq!(Document::new().find(|_, _| true), None);
+
+    // only top-level values are searched
+    assert_eq!(
+        doc.find(|_, v| matches!(v, Bson::ObjectId(_))),
+        Some(("id", &Bson::ObjectId(oid)))
+    );
+
+    assert_eq!(
+        doc.find_all(|_, v| v.as_i32().is_some()),
+        vec![("count", &Bson::Int32(1)), ("other", &Bson::Int32(2))]
+    );
+    assert!(doc.find_all(|_, v| v.as_bool().is_some()).is_empty());
+    assert_eq!(doc.find_all(|_, _| true).len(), doc.len());
+}
+
+#[test]
+fn assert_shape() {
+    let _guard = LOCK.run_concurrently();
+
+    let doc = doc! { "a": 1, "b": "x", "c": { "d": null } };
+    doc.assert_shape(&[
+        ("c", ElementType::EmbeddedDocument),
+        ("a", ElementType::Int32),
+        ("b", ElementType::String),
+    ]);
+    Document::new().assert_shape(&[]);
+
+    let message = std::panic::catch_unwind(|| {
+        doc.assert_shape(&[
+            ("a", ElementType::Int64),
+            ("b", ElementType::String),
+            ("e", ElementType::Boolean),
+        ])
+    })
+    .unwrap_err()
+    .downcast::<String>()
+    .unwrap();
+    assert!(
+        message.contains(r#""a": expected Int64, got Int32"#),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains(r#""e": missing, expected Boolean"#),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains(r#""c": unexpected EmbeddedDocument"#),
+        "{}",
+        message
+    );
+    assert!(!message.contains(r#"  "b":"#), "{}", message);
+}
+
+#[test]
+fn insert_sorted() {
+    let _guard = LOCK.run_concurrently();
+
+    let mut doc = Document::new();
+    for key in ["e", "d", "c", "b", "a"] {
+        assert_eq!(doc.insert_sorted(key, key), None);
+    }
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b", "c", "d", "e"]);
+
+    assert_eq!(
+        doc.insert_sorted("c", 3),
+        Some(Bson::String("c".to_string()))
+    );
+    assert_eq!(doc.insert_sorted("bb", 2), None);
+    assert_eq!(doc.insert_sorted("f", 6), None);
+    assert_eq!(
+        doc.keys().collect::<Vec<_>>(),
+        ["a", "b", "bb", "c", "d", "e", "f"]
+    );
+    assert_eq!(doc.get_i32("c").unwrap(), 3);
+
+    // existing keys are still replaced in an unsorted document, but an entry that the search
+    // misses is moved to the searched position rather than updated in place
+    let mut doc = doc! { "z": 1, "a": 2, "m": 3 };
+    assert_eq!(doc.insert_sorted("z", 4), Some(Bson::Int32(1)));
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "m", "z"]);
+    assert_eq!(doc.insert_sorted("m", 5), Some(Bson::Int32(3)));
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "m", "z"]);
+    assert_eq!(doc.get_i32("m").unwrap(), 5);
+
+    let mut doc = doc! { "b": 1, "c": 2, "a": 3 };
+    assert_eq!(doc.insert_sorted("a", 4), Some(Bson::Int32(3)));
+    assert_eq!(doc.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!(doc.get_i32("a").unwrap(), 4);
+}
+
+#[test]
+fn typed_iterators() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! {
+        "doc1": { "a": 1 },
+        "str1": "x",
+        "i32": 1,
+        "arr1": [1, 2],
+        "i64": 2_i64,
+        "doc2": {},
+        "str2": "y",
+        "arr2": [],
+        "null": null,
+        "f64": 1.5,
+    };
+
+    let docs: Vec<_> = doc.iter_documents().collect();
+    assert_eq!(docs, [("doc1", &doc! { "a": 1 }), ("doc2", &doc! {})]);
+
+    let arrays: Vec<_> = doc.iter_arrays().collect();
+    assert_eq!(
+        arrays,
+        [
+            ("arr1", &vec![Bson::Int32(1), Bson::Int32(2)]),
+            ("arr2", &vec![])
+        ]
+    );
+
+    let strings: Vec<_> = doc.iter_strings().collect();
+    assert_eq!(strings, [("str1", "x"), ("str2", "y")]);
+
+    let i32s: Vec<_> = doc.iter_i32().collect();
+    assert_eq!(i32s, [("i32", 1)]);
+
+    let i64s: Vec<_> = doc.iter_i64().collect();
+    assert_eq!(i64s, [("i64", 2)]);
+
+    let empty = Document::new();
+    assert_eq!(empty.iter_documents().count(), 0);
+    assert_eq!(doc! { "a": 1 }.iter_strings().count(), 0);
+}
+
+#[test]
+fn bson_conversion() {
+    let _guard = LOCK.run_concurrently();
+    let doc = doc! { "a": 1, "b": { "c": "d" } };
+
+    let bson: Bson = doc.clone().into();
+    assert_eq!(bson, Bson::Document(doc.clone()));
+    assert_eq!(Bson::from(doc.clone()), bson);
+
+    let roundtrip = Document::try_from(bson).unwrap();
+    assert_eq!(roundtrip, doc);
+    let roundtrip: Document = Bson::Document(doc.clone()).try_into().unwrap();
+    assert_eq!(roundtrip, doc);
+
+    let err = Document::try_from(Bson::Int32(1)).unwrap_err();
+    assert!(err.is_value_access_unexpected_type(), "{:?}", err);
+    assert!(err.to_string().contains("EmbeddedDocument"), "{}", err);
+    assert!(Document::try_from(Bson::Array(vec![Bson::Document(doc)])).is_err());
+}
+
 #[test]
 fn test_display_empty_doc() {
     let empty_expectation = "{}";
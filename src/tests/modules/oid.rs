@@ -73,3 +73,59 @@ fn oid_from_parts() {
     assert_eq!(&oid.bytes()[4..9], &process_id);
     assert_eq!(&oid.bytes()[9..], &counter);
 }
+
+#[test]
+fn oid_legacy_parts() {
+    let _guard = LOCK.run_concurrently();
+    let machine_id = [0xAB, 0xCD, 0xEF];
+    let process_id: u16 = 0x1234;
+    let [pid_high, pid_low] = process_id.to_be_bytes();
+    let oid = ObjectId::from_parts(
+        123,
+        [
+            machine_id[0],
+            machine_id[1],
+            machine_id[2],
+            pid_high,
+            pid_low,
+        ],
+        [9, 10, 11],
+    );
+    assert_eq!(oid.machine_id(), machine_id);
+    assert_eq!(oid.process_id(), process_id);
+
+    let oid = ObjectId::parse_str("4f2b8b5a0102030405060708").unwrap();
+    assert_eq!(oid.machine_id(), [0x01, 0x02, 0x03]);
+    assert_eq!(oid.process_id(), 0x0405);
+}
+
+#[test]
+fn oid_sequential_after() {
+    let _guard = LOCK.run_concurrently();
+
+    let oid = ObjectId::from_parts(123, [1, 2, 3, 4, 5], [0, 0, 0]);
+    let next = oid.sequential_after().unwrap();
+    assert_eq!(next, ObjectId::from_parts(123, [1, 2, 3, 4, 5], [0, 0, 1]));
+    assert_eq!(next.timestamp(), oid.timestamp());
+    assert!(oid < next);
+
+    // the increment carries between bytes
+    let oid = ObjectId::from_parts(123, [1, 2, 3, 4, 5], [0, 0xFF, 0xFF]);
+    let next = oid.sequential_after().unwrap();
+    assert_eq!(next, ObjectId::from_parts(123, [1, 2, 3, 4, 5], [1, 0, 0]));
+    assert!(oid < next);
+
+    let oid = ObjectId::from_parts(123, [1, 2, 3, 4, 5], [0xFF, 0xFF, 0xFF]);
+    assert_eq!(oid.sequential_after(), None);
+
+    let mut oid = ObjectId::new();
+    for _ in 0..10 {
+        match oid.sequential_after() {
+            Some(next) => {
+                assert!(oid < next);
+                oid = next;
+            }
+            None => break,
+        }
+    }
+}
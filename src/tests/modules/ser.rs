@@ -1,16 +1,21 @@
 use std::collections::BTreeMap;
 
 use assert_matches::assert_matches;
+use serde::Serialize;
 
 use crate::{
     deserialize_from_bson,
+    doc,
     error::ErrorKind,
     oid::ObjectId,
+    ser::{serialize_to_bson_human_readable, SerializerOptions},
+    serde_helpers::HumanReadable,
     serialize_to_bson,
     serialize_to_vec,
     tests::LOCK,
     Bson,
     Document,
+    Serializer,
 };
 
 #[test]
@@ -151,6 +156,55 @@ fn oid() {
     assert_eq!(deser, obj);
 }
 
+#[test]
+fn human_readable() {
+    let _guard = LOCK.run_concurrently();
+
+    /// Serializes as a string in human readable formats and as an integer otherwise.
+    struct Detector;
+
+    impl Serialize for Detector {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.serialize_str("human readable")
+            } else {
+                serializer.serialize_i32(0)
+            }
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Data {
+        oid: ObjectId,
+        detector: Detector,
+    }
+
+    let oid = ObjectId::new();
+    let data = Data {
+        oid,
+        detector: Detector,
+    };
+    let expected = Bson::Document(doc! { "oid": oid, "detector": "human readable" });
+    assert_eq!(serialize_to_bson_human_readable(&data).unwrap(), expected);
+    assert_eq!(
+        serialize_to_bson_human_readable(&data).unwrap(),
+        serialize_to_bson(&HumanReadable(&data)).unwrap()
+    );
+    // the default serializer is already human readable
+    assert_eq!(
+        serialize_to_bson_human_readable(&data).unwrap(),
+        serialize_to_bson(&data).unwrap()
+    );
+
+    // only explicitly opting out changes the output
+    let options = SerializerOptions::builder().human_readable(false).build();
+    let bson = data.serialize(Serializer::with_options(options)).unwrap();
+    assert_eq!(bson, Bson::Document(doc! { "oid": oid, "detector": 0 }));
+
+    let raw = crate::RawDocumentBuf::decode_from_bytes(serialize_to_vec(&data).unwrap()).unwrap();
+    assert_eq!(raw.get_i32("detector").unwrap(), 0);
+}
+
 #[test]
 fn cstring_null_bytes_error() {
     let _guard = LOCK.run_concurrently();
@@ -171,3 +225,79 @@ fn cstring_null_bytes_error() {
         assert!(result.unwrap_err().is_malformed_bytes());
     }
 }
+
+#[test]
+fn bson_size() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize)]
+    struct Flat {
+        name: String,
+        count: i32,
+        total: i64,
+        ratio: f64,
+        active: bool,
+    }
+
+    #[derive(Serialize)]
+    enum Shape {
+        Circle(f64),
+        Rectangle { width: f64, height: f64 },
+        Polygon(i32, i32, i32),
+    }
+
+    #[derive(Serialize)]
+    struct Nested {
+        id: ObjectId,
+        tags: Vec<String>,
+        shapes: Vec<Shape>,
+        extra: Document,
+        raw: crate::RawDocumentBuf,
+        optional: Option<Flat>,
+        map: BTreeMap<String, Flat>,
+    }
+
+    fn check<T: Serialize>(value: &T) {
+        assert_eq!(
+            crate::bson_size(value).unwrap(),
+            serialize_to_vec(value).unwrap().len()
+        );
+    }
+
+    let flat = || Flat {
+        name: "a".repeat(100),
+        count: 1,
+        total: 2,
+        ratio: 0.5,
+        active: true,
+    };
+    check(&flat());
+    check(&Document::new());
+    check(&doc! {
+        "code": Bson::JavaScriptCodeWithScope(crate::JavaScriptCodeWithScope {
+            code: "x".to_string(),
+            scope: doc! { "x": 1 },
+        }),
+        "binary": crate::Binary { subtype: crate::spec::BinarySubtype::Generic, bytes: vec![1; 64] },
+    });
+    check(&Nested {
+        id: ObjectId::new(),
+        tags: vec!["x".to_string(), "yz".to_string()],
+        shapes: vec![
+            Shape::Circle(1.0),
+            Shape::Rectangle {
+                width: 1.0,
+                height: 2.0,
+            },
+            Shape::Polygon(1, 2, 3),
+        ],
+        extra: doc! { "a": { "b": [1, 2, { "c": null }] } },
+        raw: crate::rawdoc! { "d": "e" },
+        optional: Some(flat()),
+        map: [("k".to_string(), flat())].into_iter().collect(),
+    });
+
+    // errors are reported in the same way as when serializing
+    assert!(crate::bson_size(&5).is_err());
+    assert!(crate::bson_size(&doc! { "a": { "\0": "b" } }).is_err());
+}
@@ -7,9 +7,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     cstr,
-    de::deserialize_from_document,
+    de::{deserialize_from_document, deserialize_with_options, DeserializerOptions},
     doc,
+    error::ErrorKind,
     oid::ObjectId,
+    ser::SerializerOptions,
     serialize_to_document,
     spec::BinarySubtype,
     tests::LOCK,
@@ -20,6 +22,7 @@ use crate::{
     JavaScriptCodeWithScope,
     RawDocumentBuf,
     Regex,
+    Serializer,
     Timestamp,
 };
 use serde_json::json;
@@ -572,3 +575,391 @@ fn test_deserialize_invalid_old_binary_length() {
     Document::decode_from_reader(&mut std::io::Cursor::new(buffer))
         .expect_err("expected deserialization to fail");
 }
+
+#[test]
+fn test_deserialize_with_options_utf8_lossy() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Debug, Deserialize)]
+    struct Foo {
+        s: String,
+    }
+
+    let invalid_string = unsafe { String::from_utf8_unchecked(b"a\x80b".to_vec()) };
+    let bytes = rawdoc! { "s": invalid_string }.into_bytes();
+
+    deserialize_with_options::<Foo>(&bytes, DeserializerOptions::default()).unwrap_err();
+
+    let options = DeserializerOptions::builder().utf8_lossy(true).build();
+    let foo: Foo = deserialize_with_options(&bytes, options).unwrap();
+    assert_eq!(foo.s, "a\u{FFFD}b");
+}
+
+#[test]
+fn test_deserialize_with_options_allow_duplicate_keys() {
+    let _guard = LOCK.run_concurrently();
+
+    let unique = rawdoc! { "a": 1, "sub": { "b": 1 }, "arr": [1, 1] }.into_bytes();
+    let mut sub = rawdoc! { "b": 1 };
+    sub.append(cstr!("b"), 2);
+    let mut duplicated = rawdoc! { "a": 1 };
+    duplicated.append(cstr!("sub"), sub);
+
+    let doc: Document =
+        deserialize_with_options(duplicated.as_bytes(), DeserializerOptions::default()).unwrap();
+    assert_eq!(doc, doc! { "a": 1, "sub": { "b": 2 } });
+
+    let options = DeserializerOptions::builder()
+        .allow_duplicate_keys(false)
+        .build();
+    let err =
+        deserialize_with_options::<Document>(duplicated.as_bytes(), options.clone()).unwrap_err();
+    assert!(err.to_string().contains("duplicate key \"b\""), "{}", err);
+
+    // repeated values in arrays are not duplicate keys
+    let doc: Document = deserialize_with_options(&unique, options).unwrap();
+    assert_eq!(doc, doc! { "a": 1, "sub": { "b": 1 }, "arr": [1, 1] });
+}
+
+#[test]
+fn test_deserialize_with_options_max_depth() {
+    let _guard = LOCK.run_concurrently();
+
+    let bytes = rawdoc! { "a": { "b": [{ "c": 1 }] } }.into_bytes();
+
+    let doc: Document = deserialize_with_options(&bytes, DeserializerOptions::default()).unwrap();
+    assert_eq!(doc, doc! { "a": { "b": [{ "c": 1 }] } });
+
+    let options = DeserializerOptions::builder().max_depth(4).build();
+    let doc: Document = deserialize_with_options(&bytes, options).unwrap();
+    assert_eq!(doc, doc! { "a": { "b": [{ "c": 1 }] } });
+
+    let options = DeserializerOptions::builder().max_depth(3).build();
+    let err = deserialize_with_options::<Document>(&bytes, options).unwrap_err();
+    assert!(err.to_string().contains("maximum nesting depth"), "{}", err);
+
+    let options = DeserializerOptions::builder().max_depth(1).build();
+    deserialize_with_options::<Document>(rawdoc! { "a": 1 }.as_bytes(), options).unwrap();
+}
+
+#[test]
+fn test_serializer_with_options_max_depth() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize)]
+    struct Inner {
+        c: i32,
+        id: ObjectId,
+        data: Binary,
+    }
+
+    #[derive(Serialize)]
+    struct Outer {
+        a: Vec<Inner>,
+    }
+
+    let value = Outer {
+        a: vec![Inner {
+            c: 1,
+            id: ObjectId::new(),
+            data: Binary {
+                subtype: BinarySubtype::UserDefined(0x80),
+                bytes: vec![1, 2, 3],
+            },
+        }],
+    };
+    let serialize = |options| value.serialize(Serializer::with_options(options));
+
+    let expected = crate::serialize_to_bson(&value).unwrap();
+    assert_eq!(serialize(SerializerOptions::default()).unwrap(), expected);
+
+    // extended JSON wrappers such as `$oid` and `$binary` do not count towards the depth
+    let options = SerializerOptions::builder().max_depth(3).build();
+    assert_eq!(serialize(options).unwrap(), expected);
+
+    let options = SerializerOptions::builder().max_depth(2).build();
+    let err = serialize(options).unwrap_err();
+    assert!(err.to_string().contains("maximum nesting depth"), "{}", err);
+
+    let options = SerializerOptions::builder().max_depth(1).build();
+    let err = doc! { "a": Bson::JavaScriptCodeWithScope(JavaScriptCodeWithScope {
+        code: "code".to_string(),
+        scope: doc! {},
+    }) }
+    .serialize(Serializer::with_options(options))
+    .unwrap_err();
+    assert!(err.to_string().contains("maximum nesting depth"), "{}", err);
+}
+
+#[test]
+fn test_serializer_with_options_deny_oversized_binary() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize)]
+    struct Foo {
+        #[serde(with = "serde_bytes")]
+        generic: Vec<u8>,
+        nested: Vec<Binary>,
+    }
+
+    let value = |generic_len, nested_len| Foo {
+        generic: vec![0; generic_len],
+        nested: vec![Binary {
+            subtype: BinarySubtype::Encrypted,
+            bytes: vec![0; nested_len],
+        }],
+    };
+    let options = SerializerOptions::builder()
+        .deny_oversized_binary(4)
+        .build();
+
+    value(4, 4)
+        .serialize(Serializer::with_options(options.clone()))
+        .unwrap();
+    for (generic_len, nested_len) in [(5, 0), (0, 5)] {
+        let err = value(generic_len, nested_len)
+            .serialize(Serializer::with_options(options.clone()))
+            .unwrap_err();
+        assert!(err.to_string().contains("maximum length"), "{}", err);
+    }
+
+    // top-level binary values are checked too
+    let err = serde_bytes::Bytes::new(&[0; 5])
+        .serialize(Serializer::with_options(options))
+        .unwrap_err();
+    assert!(err.to_string().contains("maximum length"), "{}", err);
+
+    let options = SerializerOptions::builder().human_readable(false).build();
+    assert!(!serde::Serializer::is_human_readable(
+        &Serializer::with_options(options)
+    ));
+}
+
+#[test]
+fn document_seed() {
+    use crate::de::seed::{DocumentContext, DocumentSeed};
+    use serde::de::DeserializeSeed;
+
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Default)]
+    struct Recorder {
+        keys: Vec<String>,
+        max_fields: usize,
+    }
+
+    impl DocumentContext for Recorder {
+        fn visit_field(&mut self, key: &str, _value: &Bson) -> Result<(), String> {
+            if self.keys.len() == self.max_fields {
+                return Err(format!("too many fields at {}", key));
+            }
+            self.keys.push(key.to_string());
+            Ok(())
+        }
+    }
+
+    let doc = doc! { "a": 1, "b": { "c": [1, 2] }, "d": ObjectId::new() };
+
+    let mut recorder = Recorder {
+        max_fields: 3,
+        ..Default::default()
+    };
+    let seed = DocumentSeed {
+        context: &mut recorder,
+    };
+    let deserialized = seed
+        .deserialize(crate::Deserializer::new(Bson::Document(doc.clone())))
+        .unwrap();
+    assert_eq!(deserialized, doc);
+    assert_eq!(recorder.keys, ["a", "b", "d"]);
+
+    // from BSON bytes
+    let bytes = doc.encode_to_vec().unwrap();
+    let mut recorder = Recorder {
+        max_fields: 3,
+        ..Default::default()
+    };
+    let seed = DocumentSeed {
+        context: &mut recorder,
+    };
+    let deserialized = seed
+        .deserialize(crate::de::RawDeserializer::new(&bytes).unwrap())
+        .unwrap();
+    assert_eq!(deserialized, doc);
+    assert_eq!(recorder.keys, ["a", "b", "d"]);
+
+    // errors from the context are propagated
+    let seed = DocumentSeed {
+        context: Recorder {
+            max_fields: 1,
+            ..Default::default()
+        },
+    };
+    let error = seed
+        .deserialize(crate::Deserializer::new(Bson::Document(doc)))
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("too many fields at b"),
+        "{}",
+        error
+    );
+
+    let seed = DocumentSeed {
+        context: Recorder::default(),
+    };
+    seed.deserialize(crate::Deserializer::new(Bson::Int32(1)))
+        .unwrap_err();
+}
+
+#[test]
+fn deserializer_into_inner() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct A {
+        a: i32,
+        b: B,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct B {
+        c: Vec<i32>,
+    }
+
+    // a failure partway through the document leaves the whole input in place
+    let bson = Bson::Document(doc! { "a": 1, "b": { "c": [1, "two"] } });
+    let mut deserializer = crate::Deserializer::new(bson.clone());
+    assert!(serde::Deserializer::is_human_readable(&&mut deserializer));
+    A::deserialize(&mut deserializer).unwrap_err();
+    assert_eq!(deserializer.remaining(), Some(&bson));
+
+    // the recovered value can be deserialized as something else
+    let doc = Document::deserialize(&mut deserializer).unwrap();
+    assert_eq!(Bson::Document(doc), bson);
+    assert_eq!(deserializer.remaining(), None);
+    assert!(deserializer.into_inner().is_none());
+
+    let mut deserializer = crate::Deserializer::new(bson.clone());
+    Option::<A>::deserialize(&mut deserializer).unwrap_err();
+    assert_eq!(deserializer.into_inner(), Some(bson));
+
+    // successful deserialization consumes the value
+    let bson = Bson::Document(doc! { "a": 1, "b": { "c": [1, 2] } });
+    let mut deserializer = crate::Deserializer::new(bson);
+    let a = A::deserialize(&mut deserializer).unwrap();
+    assert_eq!(
+        a,
+        A {
+            a: 1,
+            b: B { c: vec![1, 2] }
+        }
+    );
+    assert!(deserializer.remaining().is_none());
+    let error = A::deserialize(&mut deserializer).unwrap_err();
+    assert!(matches!(error.kind, ErrorKind::EndOfStream {}), "{}", error);
+
+    // the owned deserializer is unchanged
+    let deserializer = crate::Deserializer::new(Bson::Int32(1));
+    assert_eq!(deserializer.into_inner(), Some(Bson::Int32(1)));
+    let uuid = crate::Uuid::new();
+    let mut deserializer = crate::Deserializer::new(Bson::from(uuid));
+    assert_eq!(crate::Uuid::deserialize(&mut deserializer).unwrap(), uuid);
+}
+
+#[test]
+fn deserialize_from_reader() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Foo {
+        a: i32,
+        b: String,
+    }
+
+    let bytes = crate::serialize_to_vec(&doc! { "a": 1, "b": "two" }).unwrap();
+    let expected = Foo {
+        a: 1,
+        b: "two".to_string(),
+    };
+
+    let foo: Foo = crate::deserialize_from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(foo, expected);
+
+    let path = std::env::temp_dir().join(format!("bson-from-reader-{}.bson", std::process::id()));
+    std::fs::write(&path, &bytes).unwrap();
+    let file = std::fs::File::open(&path).unwrap();
+    let result: crate::error::Result<Foo> = crate::deserialize_from_reader(file);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(result.unwrap(), expected);
+
+    let truncated = &bytes[..bytes.len() - 3];
+    assert!(crate::deserialize_from_reader::<_, Foo>(truncated).is_err());
+    assert!(crate::deserialize_from_reader::<_, Foo>(&bytes[..2]).is_err());
+}
+
+#[test]
+fn bson_as_map_access() {
+    use crate::de::{bson_as_map_access, bson_as_seq_access};
+    use serde::de::{value::MapAccessDeserializer, MapAccess, SeqAccess, Visitor};
+
+    let _guard = LOCK.run_concurrently();
+
+    struct SumVisitor;
+
+    impl<'de> Visitor<'de> for SumVisitor {
+        type Value = (Vec<&'de str>, i64);
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map of integers")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut keys = Vec::new();
+            let mut sum = 0;
+            while let Some(key) = map.next_key::<&str>()? {
+                keys.push(key);
+                sum += map.next_value::<i64>()?;
+            }
+            Ok((keys, sum))
+        }
+    }
+
+    let doc = doc! { "a": 1, "b": 2_i64, "c": 3 };
+    let access = bson_as_map_access(&doc);
+    assert_eq!(access.size_hint(), Some(3));
+    let (keys, sum) = SumVisitor.visit_map(access).unwrap();
+    assert_eq!(keys, ["a", "b", "c"]);
+    assert_eq!(sum, 6);
+
+    let bad = doc! { "a": 1, "b": "two" };
+    SumVisitor.visit_map(bson_as_map_access(&bad)).unwrap_err();
+
+    // the map access can also back a derived Deserialize implementation
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Item {
+        name: String,
+        tags: Vec<String>,
+        id: ObjectId,
+    }
+
+    let id = ObjectId::new();
+    let doc = doc! { "name": "x", "tags": ["a", "b"], "id": id };
+    let item = Item::deserialize(MapAccessDeserializer::new(bson_as_map_access(&doc))).unwrap();
+    assert_eq!(
+        item,
+        Item {
+            name: "x".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+            id,
+        }
+    );
+
+    let array = vec![Bson::Int32(1), Bson::Null, Bson::Int32(3)];
+    let mut access = bson_as_seq_access(&array);
+    assert_eq!(access.size_hint(), Some(3));
+    assert_eq!(access.next_element::<Option<i32>>().unwrap(), Some(Some(1)));
+    assert_eq!(access.size_hint(), Some(2));
+    assert_eq!(access.next_element::<Option<i32>>().unwrap(), Some(None));
+    assert_eq!(access.next_element::<i32>().unwrap(), Some(3));
+    assert_eq!(access.next_element::<i32>().unwrap(), None);
+}
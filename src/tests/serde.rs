@@ -82,6 +82,32 @@ fn test_de_map() {
     assert_eq!(expected, map);
 }
 
+#[test]
+fn test_document_serde_methods() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Foo {
+        a: i32,
+        b: String,
+    }
+
+    let foo = Foo {
+        a: 1,
+        b: "x".to_string(),
+    };
+    let doc = Document::from_serde(&foo).unwrap();
+    assert_eq!(doc, doc! { "a": 1, "b": "x" });
+    assert_eq!(doc, serialize_to_document(&foo).unwrap());
+    assert_eq!(doc.to_serde::<Foo>().unwrap(), foo);
+
+    let map = BTreeMap::from([("x".to_string(), 0), ("y".to_string(), 1)]);
+    assert_eq!(Document::from_serde(&map).unwrap(), doc! { "x": 0, "y": 1 });
+
+    assert!(Document::from_serde(&5).is_err());
+    assert!(doc! { "a": "one" }.to_serde::<Foo>().is_err());
+}
+
 #[test]
 fn test_ser_timestamp() {
     let _guard = LOCK.run_concurrently();
@@ -978,6 +1004,55 @@ fn test_datetime_i64_helper() {
     );
 }
 
+#[test]
+#[cfg(feature = "serde_with-3")]
+fn test_datetime_u32_seconds_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "datetime::FromU32Seconds")]
+        date: u32,
+
+        #[serde_as(as = "Option<datetime::FromU32Seconds>")]
+        date_optional: Option<u32>,
+    }
+
+    for seconds in [0, 1_591_700_287, u32::MAX] {
+        let a = A {
+            date: seconds,
+            date_optional: Some(seconds),
+        };
+        let doc = serialize_to_document(&a).unwrap();
+        let expected = DateTime::from_millis(i64::from(seconds) * 1000);
+        assert_eq!(doc.get_datetime("date").unwrap(), &expected);
+        assert_eq!(doc.get("date_optional"), Some(&Bson::DateTime(expected)));
+        let a_deserialized: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a_deserialized, a);
+    }
+
+    // rounds to the nearest second
+    let doc = doc! {
+        "date": DateTime::from_millis(1_499),
+        "date_optional": DateTime::from_millis(1_500),
+    };
+    let a: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a.date, 1);
+    assert_eq!(a.date_optional, Some(2));
+
+    // out of range for u32 seconds
+    for date in [
+        DateTime::from_millis(-1_000),
+        DateTime::from_millis((i64::from(u32::MAX) + 1) * 1000),
+        DateTime::MAX,
+        DateTime::MIN,
+    ] {
+        let doc = doc! { "date": date, "date_optional": Bson::Null };
+        assert!(deserialize_from_document::<A>(doc).is_err());
+    }
+}
+
 #[test]
 #[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
 fn test_datetime_chrono04_datetime_helper() {
@@ -1050,6 +1125,236 @@ fn test_datetime_chrono04_datetime_helper() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
+fn test_datetime_http_date_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "datetime::AsHttpDate")]
+        pub date: DateTime,
+
+        #[serde_as(as = "Option<datetime::AsHttpDate>")]
+        pub date_optional: Option<DateTime>,
+    }
+
+    let date = DateTime::parse_rfc3339_str("2023-08-07T12:00:00Z").unwrap();
+    let a = A {
+        date,
+        date_optional: Some(date),
+    };
+
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! {
+            "date": "Mon, 07 Aug 2023 12:00:00 GMT",
+            "date_optional": "Mon, 07 Aug 2023 12:00:00 GMT",
+        }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    // the day of the week is computed from the date
+    let leap_day = DateTime::parse_rfc3339_str("2024-02-29T23:59:59.999Z").unwrap();
+    let doc = serialize_to_document(&A {
+        date: leap_day,
+        date_optional: None,
+    })
+    .unwrap();
+    assert_eq!(
+        doc.get_str("date").unwrap(),
+        "Thu, 29 Feb 2024 23:59:59 GMT"
+    );
+
+    // milliseconds are dropped
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(
+        a_deserialized.date,
+        DateTime::from_millis(leap_day.timestamp_millis() - 999)
+    );
+
+    // a mismatched day of the week is rejected
+    let bad = doc! { "date": "Tue, 07 Aug 2023 12:00:00 GMT", "date_optional": null };
+    deserialize_from_document::<A>(bad).unwrap_err();
+    let bad = doc! { "date": "2023-08-07T12:00:00Z", "date_optional": null };
+    deserialize_from_document::<A>(bad).unwrap_err();
+}
+
+#[test]
+#[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
+fn test_datetime_timezone_aware_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "datetime::AsTimezoneAware")]
+        pub date: chrono::DateTime<chrono::FixedOffset>,
+
+        #[serde_as(as = "Option<datetime::AsTimezoneAware>")]
+        pub date_optional: Option<chrono::DateTime<chrono::FixedOffset>>,
+    }
+
+    let a = A {
+        date: chrono::DateTime::parse_from_rfc3339("2023-08-07T18:00:00.123+05:30").unwrap(),
+        date_optional: Some(chrono::DateTime::parse_from_rfc3339("2023-08-07T12:30:00Z").unwrap()),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! {
+            "date": {
+                "utc": DateTime::parse_rfc3339_str("2023-08-07T12:30:00.123Z").unwrap(),
+                "tz": "+05:30",
+            },
+            "date_optional": {
+                "utc": DateTime::parse_rfc3339_str("2023-08-07T12:30:00Z").unwrap(),
+                "tz": "+00:00",
+            },
+        }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+    assert_eq!(a_deserialized.date.offset().local_minus_utc(), 19_800);
+
+    let negative = doc! {
+        "date": { "utc": DateTime::from_millis(0), "tz": "-08:00" },
+        "date_optional": null,
+    };
+    let a_deserialized: A = deserialize_from_document(negative).unwrap();
+    assert_eq!(
+        a_deserialized.date.to_rfc3339(),
+        "1969-12-31T16:00:00-08:00"
+    );
+
+    // offsets with seconds, such as Amsterdam's local mean time, round trip too
+    let lmt = chrono::FixedOffset::east_opt(19 * 60 + 32).unwrap();
+    let a = A {
+        date: DateTime::from_millis(0).to_chrono().with_timezone(&lmt),
+        date_optional: Some(
+            DateTime::from_millis(0)
+                .to_chrono()
+                .with_timezone(&chrono::FixedOffset::west_opt(3_723).unwrap()),
+        ),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc.get_document("date").unwrap().get_str("tz").unwrap(),
+        "+00:19:32"
+    );
+    assert_eq!(
+        doc.get_document("date_optional")
+            .unwrap()
+            .get_str("tz")
+            .unwrap(),
+        "-01:02:03"
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+    assert_eq!(a_deserialized.date.offset(), &lmt);
+
+    for tz in [
+        "",
+        "05:30",
+        "+5:30",
+        "+0530",
+        "+05:60",
+        "+24:00",
+        "UTC",
+        "+0a:00",
+        "+05:30:",
+        "+05:30:60",
+        "+05:30:0",
+        "+05:30:00:00",
+    ] {
+        let bad = doc! {
+            "date": { "utc": DateTime::from_millis(0), "tz": tz },
+            "date_optional": null,
+        };
+        assert!(deserialize_from_document::<A>(bad).is_err(), "{}", tz);
+    }
+    let bad = doc! { "date": { "utc": DateTime::from_millis(0) }, "date_optional": null };
+    deserialize_from_document::<A>(bad).unwrap_err();
+    let bad = doc! { "date": DateTime::from_millis(0), "date_optional": null };
+    deserialize_from_document::<A>(bad).unwrap_err();
+}
+
+#[test]
+#[cfg(all(feature = "chrono-0_4", feature = "serde_with-3"))]
+fn test_datetime_string_with_format_helper() {
+    use serde_helpers::datetime::DateTimeFormat;
+
+    let _guard = LOCK.run_concurrently();
+
+    struct SlashDate;
+    impl DateTimeFormat for SlashDate {
+        const FORMAT: &'static str = "%Y/%m/%d";
+    }
+
+    struct WithOffset;
+    impl DateTimeFormat for WithOffset {
+        const FORMAT: &'static str = "%d.%m.%Y %H:%M %z";
+    }
+
+    struct Invalid;
+    impl DateTimeFormat for Invalid {
+        const FORMAT: &'static str = "%Y %Q";
+    }
+
+    #[serde_as]
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "datetime::FromStringWithFormat<SlashDate>")]
+        pub date: String,
+
+        #[serde_as(as = "Option<datetime::FromStringWithFormat<WithOffset>>")]
+        pub date_optional: Option<String>,
+    }
+
+    let a = A {
+        date: "2023/08/07".to_string(),
+        date_optional: Some("07.08.2023 14:30 +0200".to_string()),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! {
+            "date": DateTime::parse_rfc3339_str("2023-08-07T00:00:00Z").unwrap(),
+            "date_optional": DateTime::parse_rfc3339_str("2023-08-07T12:30:00Z").unwrap(),
+        }
+    );
+
+    // offsets are normalized to UTC
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized.date, a.date);
+    assert_eq!(
+        a_deserialized.date_optional.as_deref(),
+        Some("07.08.2023 12:30 +0000")
+    );
+
+    let a = A {
+        date: "2023-08-07".to_string(),
+        date_optional: None,
+    };
+    serialize_to_document(&a).unwrap_err();
+
+    #[serde_as]
+    #[derive(Deserialize, Serialize, Debug)]
+    struct B {
+        #[serde_as(as = "datetime::FromStringWithFormat<Invalid>")]
+        pub date: String,
+    }
+
+    serialize_to_document(&B {
+        date: "2023 x".to_string(),
+    })
+    .unwrap_err();
+    deserialize_from_document::<B>(doc! { "date": DateTime::now() }).unwrap_err();
+}
+
 #[test]
 #[cfg(all(feature = "time-0_3", feature = "serde_with-3"))]
 fn test_datetime_time03_offset_datetime_helper() {
@@ -1374,6 +1679,192 @@ fn test_uuid_1_helpers() {
     assert_eq!(a.uuid, uuid);
 }
 
+#[test]
+fn test_int_as_f64_helpers() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::i32_as_f64")]
+        pub small: i32,
+        #[serde(with = "serde_helpers::i64_as_f64")]
+        pub large: i64,
+    }
+
+    for (small, large) in [
+        (0, 0),
+        (42, 42),
+        (-7, -7),
+        (i32::MAX, 1 << 53),
+        (i32::MIN, -(1 << 53)),
+        (1, 1 << 62),
+        (1, i64::MIN),
+    ] {
+        let a = A { small, large };
+        let doc = serialize_to_document(&a).unwrap();
+        assert_eq!(
+            doc,
+            doc! { "small": f64::from(small), "large": large as f64 }
+        );
+        let a_deserialized: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a_deserialized, a);
+    }
+
+    let a: A = deserialize_from_document(doc! { "small": 42.0, "large": -0.0 }).unwrap();
+    assert_eq!(
+        a,
+        A {
+            small: 42,
+            large: 0
+        }
+    );
+
+    for small in [
+        42.5,
+        f64::from(i32::MAX) + 1.0,
+        f64::from(i32::MIN) - 1.0,
+        f64::NAN,
+        f64::INFINITY,
+    ] {
+        let doc = doc! { "small": small, "large": 0.0 };
+        assert!(deserialize_from_document::<A>(doc).is_err(), "{}", small);
+    }
+    for large in [
+        0.5,
+        2f64.powi(63),
+        -2f64.powi(64),
+        f64::NAN,
+        f64::NEG_INFINITY,
+    ] {
+        let doc = doc! { "small": 0.0, "large": large };
+        assert!(deserialize_from_document::<A>(doc).is_err(), "{}", large);
+    }
+
+    // i64 values that an f64 cannot represent exactly
+    for large in [(1 << 53) + 1, i64::MAX, i64::MIN + 1] {
+        assert!(
+            serialize_to_document(&A { small: 0, large }).is_err(),
+            "{}",
+            large
+        );
+    }
+}
+
+#[test]
+fn test_f32_as_f64_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Serialize, Debug)]
+    struct A {
+        #[serde(with = "serde_helpers::f32_as_f64")]
+        pub value: f32,
+    }
+
+    for value in [
+        0.0,
+        -2.5,
+        0.1,
+        f32::MAX,
+        f32::MIN,
+        f32::MIN_POSITIVE,
+        f32::INFINITY,
+    ] {
+        let a = A { value };
+        let doc = serialize_to_document(&a).unwrap();
+        assert_eq!(doc.get_f64("value").unwrap(), f64::from(value));
+        let a: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a.value, value);
+    }
+
+    let doc = serialize_to_document(&A { value: f32::NAN }).unwrap();
+    assert!(doc.get_f64("value").unwrap().is_nan());
+    let a: A = deserialize_from_document(doc).unwrap();
+    assert!(a.value.is_nan());
+
+    // values with more precision than an f32 can hold are rejected rather than rounded
+    for value in [
+        0.1f64,
+        1.0 + f64::EPSILON,
+        f64::from(f32::MIN_POSITIVE) / 3.0,
+    ] {
+        let err = deserialize_from_document::<A>(doc! { "value": value }).unwrap_err();
+        assert!(err.to_string().contains("losing precision"), "{}", err);
+    }
+
+    for value in [f64::MAX, -1e39] {
+        let err = deserialize_from_document::<A>(doc! { "value": value }).unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{}", err);
+    }
+}
+
+#[test]
+#[cfg(feature = "serde_with-3")]
+fn test_small_float_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "serde_helpers::small_float::AsF64")]
+        pub value: f32,
+
+        #[serde_as(as = "Vec<serde_helpers::small_float::AsF64>")]
+        pub values: Vec<f32>,
+    }
+
+    let a = A {
+        value: 2.5,
+        values: vec![f32::MAX, f32::NEG_INFINITY],
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! { "value": 2.5, "values": [f64::from(f32::MAX), f64::NEG_INFINITY] }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let doc = doc! { "value": 1e300, "values": [] };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+    let doc = doc! { "value": 2.5, "values": [0.1f64] };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+}
+
+#[test]
+fn test_i64_as_datetime_millis_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::i64_as_datetime_millis")]
+        pub date: i64,
+    }
+
+    for millis in [
+        0,
+        1_591_700_287_095,
+        -1,
+        DateTime::MAX.timestamp_millis(),
+        DateTime::MIN.timestamp_millis(),
+    ] {
+        let a = A { date: millis };
+        let doc = serialize_to_document(&a).unwrap();
+        assert_eq!(
+            doc.get_datetime("date").unwrap(),
+            &DateTime::from_millis(millis)
+        );
+        let a_deserialized: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a_deserialized, a);
+
+        let bytes = crate::serialize_to_vec(&a).unwrap();
+        let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(a_deserialized, a);
+    }
+
+    let doc = doc! { "date": 5i64 };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+}
+
 #[test]
 fn test_timestamp_helpers() {
     let _guard = LOCK.run_concurrently();
@@ -1415,6 +1906,941 @@ fn test_timestamp_helpers() {
     assert!(serde_json::to_value(b).is_err());
 }
 
+#[test]
+fn test_timestamp_time_as_u32_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::timestamp_time_as_u32")]
+        pub timestamp: Timestamp,
+    }
+
+    let a = A {
+        timestamp: Timestamp {
+            time: 12345,
+            increment: 7,
+        },
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, doc! { "timestamp": 12345i64 });
+    let a: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(
+        a.timestamp,
+        Timestamp {
+            time: 12345,
+            increment: 0
+        }
+    );
+
+    let val = serde_json::to_value(&a).unwrap();
+    assert_eq!(val["timestamp"], 12345);
+    let a_deserialized: A = serde_json::from_value(val).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let a = A {
+        timestamp: Timestamp {
+            time: u32::MAX,
+            increment: u32::MAX,
+        },
+    };
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a.timestamp.time, u32::MAX);
+    assert_eq!(a.timestamp.increment, 0);
+}
+
+#[test]
+fn test_tuple_as_document_helpers() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::tuple_as_document")]
+        entry: (String, Bson),
+    }
+
+    let a = A {
+        entry: ("color".to_string(), Bson::String("blue".to_string())),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, doc! { "entry": { "k": "color", "v": "blue" } });
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct B<'a> {
+        #[serde(with = "serde_helpers::tuple_as_document", borrow)]
+        entry: (&'a str, i32),
+    }
+
+    let b = B { entry: ("x", 5) };
+    let bytes = crate::serialize_to_vec(&b).unwrap();
+    let b_deserialized: B = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(b_deserialized, b);
+
+    let missing_value = doc! { "entry": { "k": "color" } };
+    assert!(deserialize_from_document::<A>(missing_value).is_err());
+    let extra_field = doc! { "entry": { "k": "color", "v": "blue", "x": 1 } };
+    assert!(deserialize_from_document::<A>(extra_field).is_err());
+    let not_a_document = doc! { "entry": "color" };
+    assert!(deserialize_from_document::<A>(not_a_document).is_err());
+
+    #[cfg(feature = "serde_with-3")]
+    {
+        use serde_helpers::tuple_as_document::TupleAsDocument;
+
+        #[serde_as]
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        struct C {
+            #[serde_as(as = "Vec<TupleAsDocument>")]
+            entries: Vec<(String, i32)>,
+        }
+
+        let c = C {
+            entries: vec![("a".to_string(), 1), ("b".to_string(), 2)],
+        };
+        let doc = serialize_to_document(&c).unwrap();
+        assert_eq!(
+            doc,
+            doc! { "entries": [{ "k": "a", "v": 1 }, { "k": "b", "v": 2 }] }
+        );
+        let c_deserialized: C = deserialize_from_document(doc).unwrap();
+        assert_eq!(c_deserialized, c);
+    }
+}
+
+#[test]
+fn type_mismatch_errors_use_type_name() {
+    let _guard = LOCK.run_concurrently();
+
+    let value = Bson::Int32(5);
+    let err = deserialize_from_bson::<Timestamp>(value.clone()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("Timestamp"), "{}", message);
+    assert!(message.contains(value.type_name()), "{}", message);
+
+    let value = Bson::String("x".to_string());
+    let err = deserialize_from_bson::<crate::DateTime>(value.clone()).unwrap_err();
+    assert!(err.to_string().contains(value.type_name()), "{}", err);
+
+    let value = Bson::Boolean(true);
+    let err = deserialize_from_bson::<crate::Binary>(value.clone()).unwrap_err();
+    assert!(err.to_string().contains(value.type_name()), "{}", err);
+
+    let value = Bson::Document(doc! { "a": 1 });
+    let err = deserialize_from_bson::<ObjectId>(value.clone()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains(value.type_name()), "{}", message);
+    assert!(!message.contains("\"a\""), "{}", message);
+
+    let value = Bson::Int32(5);
+    let err = deserialize_from_bson::<Document>(value.clone()).unwrap_err();
+    assert!(err.to_string().contains(value.type_name()), "{}", err);
+
+    // the raw deserializer and raw value types report the type name rather than the value
+    #[derive(Deserialize, Debug)]
+    enum Unit {
+        A,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Raw {
+        #[allow(dead_code)]
+        unit: Unit,
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct RawDoc {
+        #[allow(dead_code)]
+        doc: crate::RawDocumentBuf,
+    }
+
+    let bytes = crate::serialize_to_vec(&doc! { "unit": 5, "doc": 5 }).unwrap();
+    let err = crate::deserialize_from_slice::<Raw>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("instead got Int32"), "{}", err);
+    let err = crate::deserialize_from_slice::<RawDoc>(&bytes).unwrap_err();
+    assert!(err.to_string().contains("instead got Int32"), "{}", err);
+
+    let value = Bson::String("x".to_string());
+    let err =
+        deserialize_from_bson::<crate::RawJavaScriptCodeWithScope>(value.clone()).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("but got String instead"), "{}", message);
+    assert!(!message.contains("\"x\""), "{}", message);
+}
+
+#[test]
+fn test_oid_as_inner_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct MyId(ObjectId);
+
+    impl From<ObjectId> for MyId {
+        fn from(oid: ObjectId) -> Self {
+            Self(oid)
+        }
+    }
+
+    impl From<MyId> for ObjectId {
+        fn from(id: MyId) -> Self {
+            id.0
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "object_id::as_inner")]
+        id: MyId,
+    }
+
+    let oid = ObjectId::new();
+    let a = A { id: MyId(oid) };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, doc! { "id": oid });
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+}
+
+#[test]
+fn test_oid_as_uuid_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "object_id::AsUuid")]
+        id: ObjectId,
+    }
+
+    let oid = ObjectId::from_bytes([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    let a = A { id: oid };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! {
+            "id": Binary {
+                subtype: BinarySubtype::Uuid,
+                bytes: vec![0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+            }
+        }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let doc = doc! {
+        "id": Binary {
+            subtype: BinarySubtype::Uuid,
+            bytes: vec![0, 0, 0, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+        }
+    };
+    let err = deserialize_from_document::<A>(doc).unwrap_err();
+    assert!(err.to_string().contains("first four bytes"), "{}", err);
+}
+
+#[test]
+fn test_timestamp_human_readable_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        plain: Timestamp,
+        #[serde_as(as = "serde_helpers::timestamp::AsHumanReadable")]
+        readable: Timestamp,
+        #[serde_as(as = "Option<serde_helpers::timestamp::AsHumanReadable>")]
+        optional: Option<Timestamp>,
+    }
+
+    let ts = Timestamp {
+        time: u32::MAX,
+        increment: 7,
+    };
+    let a = A {
+        plain: ts,
+        readable: ts,
+        optional: Some(ts),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc.get_timestamp("plain").unwrap(), ts);
+    let readable = doc.get_document("readable").unwrap();
+    assert_eq!(readable.keys().collect::<Vec<_>>(), ["time", "increment"]);
+    assert_eq!(readable.get_i64("time").unwrap(), u32::MAX as i64);
+    assert_eq!(readable.get_i64("increment").unwrap(), 7);
+    assert_eq!(doc.get_document("optional").unwrap(), readable);
+
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    // a BSON timestamp is not accepted by the converter
+    let doc = doc! { "plain": ts, "readable": ts, "optional": null };
+    deserialize_from_document::<A>(doc).unwrap_err();
+}
+
+#[test]
+fn test_skip_unknown_fields() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    #[serde(deny_unknown_fields)]
+    struct Inner {
+        b: i32,
+    }
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    #[serde(deny_unknown_fields)]
+    struct A {
+        name: String,
+        inner: Inner,
+        #[serde(default)]
+        count: i32,
+        #[serde(rename = "renamed")]
+        value: bool,
+    }
+
+    let expected = A {
+        name: "x".to_string(),
+        inner: Inner { b: 1 },
+        count: 0,
+        value: true,
+    };
+    let doc = doc! {
+        "extra": 1,
+        "name": "x",
+        "unknown": { "nested": [1, 2, 3] },
+        "inner": { "b": 1 },
+        "value": false,
+        "renamed": true,
+    };
+
+    let err = deserialize_from_document::<A>(doc.clone()).unwrap_err();
+    assert!(err.to_string().contains("unknown field"), "{}", err);
+    let bytes = crate::serialize_to_vec(&doc).unwrap();
+    crate::deserialize_from_slice::<A>(&bytes).unwrap_err();
+
+    let a: serde_helpers::SkipUnknownFields<A> = deserialize_from_document(doc.clone()).unwrap();
+    assert_eq!(a.0, expected);
+    let a: serde_helpers::SkipUnknownFields<A> = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a.0, expected);
+
+    // serialization is unaffected
+    assert_eq!(
+        serialize_to_document(&a).unwrap(),
+        serialize_to_document(&expected).unwrap()
+    );
+
+    // only the fields of the wrapped type are affected
+    let doc = doc! { "name": "x", "inner": { "b": 1, "c": 2 }, "renamed": true };
+    deserialize_from_document::<serde_helpers::SkipUnknownFields<A>>(doc).unwrap_err();
+
+    // missing required fields are still reported
+    let doc = doc! { "name": "x", "extra": 1 };
+    let err = deserialize_from_document::<serde_helpers::SkipUnknownFields<A>>(doc).unwrap_err();
+    assert!(err.to_string().contains("missing field"), "{}", err);
+}
+
+#[test]
+fn test_binary_as_hex_string_helpers() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::binary_as_hex_string")]
+        generic: Binary,
+        #[serde(with = "serde_helpers::binary_as_hex_string_with_subtype")]
+        with_subtype: Binary,
+    }
+
+    let a = A {
+        generic: Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: vec![0x0a, 0x0b, 0xff],
+        },
+        with_subtype: Binary {
+            subtype: BinarySubtype::UserDefined(0x80),
+            bytes: vec![1, 2],
+        },
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, doc! { "generic": "0a0bff", "with_subtype": "80:0102" });
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let a = A {
+        generic: Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: Vec::new(),
+        },
+        with_subtype: Binary {
+            subtype: BinarySubtype::Generic,
+            bytes: Vec::new(),
+        },
+    };
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    // the subtype is only preserved with the prefix; without it, it defaults to generic
+    let uuid = Binary {
+        subtype: BinarySubtype::Uuid,
+        bytes: vec![0; 16],
+    };
+    let a = A {
+        generic: uuid.clone(),
+        with_subtype: uuid.clone(),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! {
+            "generic": "00000000000000000000000000000000",
+            "with_subtype": "04:00000000000000000000000000000000",
+        }
+    );
+    let a: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a.generic.subtype, BinarySubtype::Generic);
+    assert_eq!(a.generic.bytes, uuid.bytes);
+    assert_eq!(a.with_subtype, uuid);
+
+    // uppercase hex is accepted
+    let a: A =
+        deserialize_from_document(doc! { "generic": "0A0B", "with_subtype": "0A:0B" }).unwrap();
+    assert_eq!(a.generic.bytes, vec![0x0a, 0x0b]);
+    assert_eq!(a.with_subtype.subtype, BinarySubtype::from(0x0a));
+    assert_eq!(a.with_subtype.bytes, vec![0x0b]);
+
+    for (generic, with_subtype) in [
+        ("xyz", "00:00"),
+        ("abc", "00:00"),
+        ("00", "0000"),
+        ("00", "zz:00"),
+        ("00", "0001:00"),
+        ("00", ":00"),
+        ("00", "00:0"),
+    ] {
+        let doc = doc! { "generic": generic, "with_subtype": with_subtype };
+        assert!(
+            deserialize_from_document::<A>(doc.clone()).is_err(),
+            "{}",
+            doc
+        );
+    }
+
+    // binary values are not accepted in place of the hex string
+    let doc = doc! { "generic": uuid.clone(), "with_subtype": "04:00" };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+}
+
+#[test]
+fn test_string_as_decimal128_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::string_as_decimal128")]
+        value: String,
+    }
+
+    for value in [
+        "0",
+        "-1.5",
+        "3.14",
+        "1.05E+3",
+        "NaN",
+        "Infinity",
+        "-Infinity",
+    ] {
+        let a = A {
+            value: value.to_string(),
+        };
+        let doc = serialize_to_document(&a).unwrap();
+        assert_eq!(
+            doc,
+            doc! { "value": value.parse::<crate::Decimal128>().unwrap() }
+        );
+        let a_deserialized: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a_deserialized, a);
+
+        let bytes = crate::serialize_to_vec(&a).unwrap();
+        let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(a_deserialized, a);
+    }
+
+    let err = serialize_to_document(&A {
+        value: "not a number".to_string(),
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("not a number"), "{}", err);
+    deserialize_from_document::<A>(doc! { "value": "3.14" }).unwrap_err();
+}
+
+#[test]
+fn test_coerce_to_string_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::coerce_to_string")]
+        value: String,
+    }
+
+    let oid = ObjectId::new();
+    let cases = [
+        (Bson::String("hello".to_string()), "hello".to_string()),
+        (Bson::Int32(42), "42".to_string()),
+        (Bson::Int64(-42), "-42".to_string()),
+        (Bson::Double(2.5), "2.5".to_string()),
+        (Bson::Boolean(true), "true".to_string()),
+        (
+            Bson::Decimal128("1.05E+3".parse().unwrap()),
+            "1.05E+3".to_string(),
+        ),
+        (Bson::ObjectId(oid), oid.to_hex()),
+    ];
+    for (bson, expected) in cases {
+        let doc = doc! { "value": bson };
+        let a: A = deserialize_from_document(doc.clone()).unwrap();
+        assert_eq!(a.value, expected);
+
+        let bytes = crate::serialize_to_vec(&doc).unwrap();
+        let a: A = crate::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(a.value, expected);
+
+        // serialization passes the string through unchanged
+        assert_eq!(
+            serialize_to_document(&a).unwrap(),
+            doc! { "value": expected }
+        );
+    }
+
+    for value in [
+        Bson::Document(doc! { "a": 1 }),
+        Bson::Array(vec![Bson::Int32(1)]),
+        Bson::Null,
+    ] {
+        let err = deserialize_from_document::<A>(doc! { "value": value }).unwrap_err();
+        assert!(err.to_string().contains("cannot coerce"), "{}", err);
+    }
+}
+
+#[test]
+fn test_flatten_optional_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        name: String,
+        #[serde(flatten, with = "serde_helpers::flatten_optional")]
+        extra: Option<Document>,
+    }
+
+    let a = A {
+        name: "x".to_string(),
+        extra: Some(doc! { "count": 1, "nested": { "b": true } }),
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! { "name": "x", "count": 1, "nested": { "b": true } }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let a = A {
+        name: "x".to_string(),
+        extra: None,
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, doc! { "name": "x" });
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+}
+
+#[test]
+fn test_generic_binary_bytes_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::binary::as_generic_bytes")]
+        data: Vec<u8>,
+    }
+
+    let a = A {
+        data: vec![1, 2, 3],
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! { "data": Binary { subtype: BinarySubtype::Generic, bytes: vec![1, 2, 3] } }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let doc = doc! { "data": Binary { subtype: BinarySubtype::Uuid, bytes: vec![0; 16] } };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+
+    let doc = doc! { "data": "not binary" };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+}
+
+#[test]
+fn test_raw_bson_value() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Inner {
+        x: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        id: i32,
+        inner: serde_helpers::RawBsonValue<Inner>,
+        list: serde_helpers::RawBsonValue<Vec<String>>,
+        name: String,
+    }
+
+    let doc = doc! {
+        "id": 1,
+        "inner": { "x": 5 },
+        "list": ["a", "b"],
+        "name": "test",
+    };
+    let bytes = crate::serialize_to_vec(&doc).unwrap();
+
+    let from_slice: A = crate::deserialize_from_slice(&bytes).unwrap();
+    let from_document: A = deserialize_from_document(doc.clone()).unwrap();
+    assert_eq!(from_slice, from_document);
+
+    for a in [from_slice, from_document] {
+        assert_eq!(a.id, 1);
+        assert_eq!(a.name, "test");
+        assert_eq!(
+            a.inner.as_raw(),
+            &crate::RawBson::Document(crate::rawdoc! { "x": 5 })
+        );
+        assert_eq!(a.inner.parse().unwrap(), Inner { x: 5 });
+        assert_eq!(a.list.parse().unwrap(), vec!["a", "b"]);
+        assert_eq!(serialize_to_document(&a).unwrap(), doc);
+    }
+
+    // values that don't match the target type are only rejected when parsed
+    let doc = doc! { "id": 1, "inner": { "x": "five" }, "list": 1, "name": "test" };
+    let a: A = deserialize_from_document(doc).unwrap();
+    assert!(a.inner.parse().is_err());
+    assert!(a.list.parse().is_err());
+}
+
+#[test]
+fn test_null_as_default_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::null_as_default")]
+        int: i32,
+        #[serde(with = "serde_helpers::null_as_default")]
+        string: String,
+        #[serde(with = "serde_helpers::null_as_default")]
+        list: Vec<i32>,
+    }
+
+    let nulls = doc! { "int": null, "string": null, "list": null };
+    let expected = A {
+        int: 0,
+        string: String::new(),
+        list: Vec::new(),
+    };
+    let a: A = deserialize_from_document(nulls.clone()).unwrap();
+    assert_eq!(a, expected);
+    let bytes = crate::serialize_to_vec(&nulls).unwrap();
+    let a: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a, expected);
+    assert_eq!(
+        serialize_to_document(&a).unwrap(),
+        doc! { "int": 0, "string": "", "list": [] }
+    );
+
+    let a = A {
+        int: 5,
+        string: "x".to_string(),
+        list: vec![1, 2],
+    };
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, doc! { "int": 5, "string": "x", "list": [1, 2] });
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    // mismatched types are still rejected
+    let doc = doc! { "int": "5", "string": null, "list": null };
+    assert!(deserialize_from_document::<A>(doc).is_err());
+}
+
+#[test]
+fn test_raw_document_and_array_helpers() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::raw_document")]
+        doc: crate::RawDocumentBuf,
+        #[serde(with = "serde_helpers::raw_array")]
+        arr: crate::RawArrayBuf,
+    }
+
+    let a = A {
+        doc: rawdoc! { "x": 1, "y": { "z": "a" } },
+        arr: crate::RawArrayBuf::try_from(vec![Bson::Int32(1), Bson::String("b".into())]).unwrap(),
+    };
+    let expected = doc! { "doc": { "x": 1, "y": { "z": "a" } }, "arr": [1, "b"] };
+
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(doc, expected);
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    let bytes = crate::serialize_to_vec(&a).unwrap();
+    assert_eq!(bytes, expected.encode_to_vec().unwrap());
+    let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    deserialize_from_document::<A>(doc! { "doc": 1, "arr": [] }).unwrap_err();
+    deserialize_from_document::<A>(doc! { "doc": {}, "arr": {} }).unwrap_err();
+}
+
+#[test]
+fn test_option_string_empty_as_none_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::option_string_empty_as_none")]
+        name: Option<String>,
+    }
+
+    for (value, expected) in [
+        (Bson::String(String::new()), None),
+        (Bson::String("x".to_string()), Some("x".to_string())),
+        (Bson::String(" ".to_string()), Some(" ".to_string())),
+        (Bson::Null, None),
+    ] {
+        let a: A = deserialize_from_document(doc! { "name": value.clone() }).unwrap();
+        assert_eq!(a.name, expected, "{:?}", value);
+
+        let bytes = crate::serialize_to_vec(&doc! { "name": value.clone() }).unwrap();
+        let a: A = crate::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(a.name, expected, "{:?}", value);
+    }
+    deserialize_from_document::<A>(doc! { "name": 1 }).unwrap_err();
+
+    let doc = serialize_to_document(&A { name: None }).unwrap();
+    assert_eq!(doc, doc! { "name": null });
+    let doc = serialize_to_document(&A {
+        name: Some("x".to_string()),
+    })
+    .unwrap();
+    assert_eq!(doc, doc! { "name": "x" });
+}
+
+#[test]
+fn test_datetime_option_as_rfc3339_string_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::datetime_option_as_rfc3339_string")]
+        date: Option<DateTime>,
+    }
+
+    let date = DateTime::parse_rfc3339_str("2020-06-09T10:58:07.095Z").unwrap();
+    for (a, expected) in [
+        (
+            A { date: Some(date) },
+            doc! { "date": "2020-06-09T10:58:07.095Z" },
+        ),
+        (A { date: None }, doc! { "date": null }),
+    ] {
+        let doc = serialize_to_document(&a).unwrap();
+        assert_eq!(doc, expected);
+        let a_deserialized: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a_deserialized, a);
+
+        let bytes = crate::serialize_to_vec(&a).unwrap();
+        let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(a_deserialized, a);
+    }
+
+    // offsets are normalized to UTC
+    let a: A = deserialize_from_document(doc! { "date": "2020-06-09T16:28:07.095+05:30" }).unwrap();
+    assert_eq!(a.date, Some(date));
+
+    let error = deserialize_from_document::<A>(doc! { "date": "not a date" }).unwrap_err();
+    assert!(error.to_string().contains("not a date"), "{}", error);
+    deserialize_from_document::<A>(doc! { "date": date }).unwrap_err();
+    deserialize_from_document::<A>(doc! { "date": 1 }).unwrap_err();
+}
+
+#[test]
+fn test_array_of_documents_as_hashmap_helper() {
+    use std::collections::HashMap;
+
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::array_of_documents_as_hashmap")]
+        rows: Vec<HashMap<String, Bson>>,
+    }
+
+    let doc = doc! {
+        "rows": [
+            { "score": 99, "name": "Alice" },
+            { "name": "Bob", "score": 1.5, "tags": ["x"] },
+            {},
+        ],
+    };
+    let a: A = deserialize_from_document(doc.clone()).unwrap();
+    assert_eq!(a.rows.len(), 3);
+    assert_eq!(a.rows[0]["name"], Bson::String("Alice".to_string()));
+    assert_eq!(a.rows[0]["score"], Bson::Int32(99));
+    assert_eq!(a.rows[1]["score"], Bson::Double(1.5));
+    assert_eq!(a.rows[1]["tags"], bson!(["x"]));
+    assert!(a.rows[2].is_empty());
+
+    let bytes = crate::serialize_to_vec(&doc).unwrap();
+    let a_from_slice: A = crate::deserialize_from_slice(&bytes).unwrap();
+    assert_eq!(a_from_slice, a);
+
+    // keys are sorted when serializing
+    let serialized = serialize_to_document(&a).unwrap();
+    assert_eq!(serialized, doc);
+    let rows = serialized.get_array("rows").unwrap();
+    let keys: Vec<Vec<&String>> = rows
+        .iter()
+        .map(|row| row.as_document().unwrap().keys().collect())
+        .collect();
+    assert_eq!(
+        keys,
+        [vec!["name", "score"], vec!["name", "score", "tags"], vec![]]
+    );
+
+    let a: A = deserialize_from_document(doc! { "rows": [] }).unwrap();
+    assert!(a.rows.is_empty());
+    assert_eq!(serialize_to_document(&a).unwrap(), doc! { "rows": [] });
+
+    deserialize_from_document::<A>(doc! { "rows": [{ "a": 1 }, 2] }).unwrap_err();
+    deserialize_from_document::<A>(doc! { "rows": { "a": 1 } }).unwrap_err();
+}
+
+#[test]
+fn test_document_as_vec_of_pairs_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde(with = "serde_helpers::document_as_vec_of_pairs")]
+        fields: Document,
+    }
+
+    for (fields, expected) in [
+        (doc! {}, bson!([])),
+        (doc! { "a": 1 }, bson!([["a", 1]])),
+        (
+            doc! { "z": 1, "a": { "nested": true }, "m": [1, 2] },
+            bson!([["z", 1], ["a", { "nested": true }], ["m", [1, 2]]]),
+        ),
+    ] {
+        let a = A { fields };
+        let doc = serialize_to_document(&a).unwrap();
+        assert_eq!(doc.get("fields"), Some(&expected));
+
+        let a_deserialized: A = deserialize_from_document(doc).unwrap();
+        assert_eq!(a_deserialized, a);
+        assert!(a_deserialized.fields.keys().eq(a.fields.keys()));
+
+        let bytes = crate::serialize_to_vec(&a).unwrap();
+        let a_deserialized: A = crate::deserialize_from_slice(&bytes).unwrap();
+        assert_eq!(a_deserialized, a);
+    }
+
+    let a: A = deserialize_from_document(doc! { "fields": [["a", 1], ["a", 2]] }).unwrap();
+    assert_eq!(a.fields, doc! { "a": 2 });
+
+    deserialize_from_document::<A>(doc! { "fields": [["a"]] }).unwrap_err();
+    deserialize_from_document::<A>(doc! { "fields": { "a": 1 } }).unwrap_err();
+}
+
+#[test]
+#[cfg(feature = "serde_with-3")]
+fn test_document_as_vec_pairs_helper() {
+    let _guard = LOCK.run_concurrently();
+
+    #[serde_as]
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct A {
+        #[serde_as(as = "serde_helpers::document::AsVecPairs")]
+        fields: Document,
+        #[serde_as(as = "Option<serde_helpers::document::AsVecPairs>")]
+        fields_optional: Option<Document>,
+    }
+
+    let a = A {
+        fields: doc! { "z": 1, "a": { "nested": true }, "m": [1, 2] },
+        fields_optional: Some(Document::new()),
+    };
+
+    let doc = serialize_to_document(&a).unwrap();
+    assert_eq!(
+        doc,
+        doc! {
+            "fields": [["z", 1], ["a", { "nested": true }], ["m", [1, 2]]],
+            "fields_optional": [],
+        }
+    );
+    let a_deserialized: A = deserialize_from_document(doc).unwrap();
+    assert_eq!(a_deserialized, a);
+    assert!(a_deserialized.fields.keys().eq(["z", "a", "m"]));
+
+    let json = serde_json::to_value(&a).unwrap();
+    assert_eq!(
+        json["fields"],
+        json!([["z", 1], ["a", { "nested": true }], ["m", [1, 2]]])
+    );
+    let a_deserialized: A = serde_json::from_value(json).unwrap();
+    assert_eq!(a_deserialized, a);
+
+    deserialize_from_document::<A>(doc! { "fields": [["a"]], "fields_optional": null })
+        .unwrap_err();
+    deserialize_from_document::<A>(doc! { "fields": { "a": 1 }, "fields_optional": null })
+        .unwrap_err();
+}
+
 #[test]
 fn large_dates() {
     let _guard = LOCK.run_concurrently();
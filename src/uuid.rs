@@ -449,6 +449,30 @@ impl Binary {
     pub fn to_uuid(&self) -> Result<Uuid> {
         self.to_uuid_with_representation(UuidRepresentation::Standard)
     }
+
+    /// Parses a UUID string (e.g. `"00112233-4455-6677-8899-aabbccddeeff"`) into a BSON [`Binary`]
+    /// with the [`BinarySubtype::Uuid`] subtype. This is equivalent to
+    /// `Binary::from_uuid(Uuid::parse_str(s)?)`.
+    ///
+    /// ```
+    /// use bson::{spec::BinarySubtype, Binary};
+    ///
+    /// let bin = Binary::from_uuid_str("00112233-4455-6677-8899-aabbccddeeff")?;
+    /// assert_eq!(bin.subtype, BinarySubtype::Uuid);
+    /// assert_eq!(bin.to_uuid_str()?, "00112233-4455-6677-8899-aabbccddeeff");
+    ///
+    /// # Ok::<(), Box::<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_uuid_str(s: &str) -> Result<Self> {
+        Uuid::parse_str(s).map(Binary::from_uuid)
+    }
+
+    /// Formats a BSON [`Binary`] holding a UUID in the standard representation as a hyphenated
+    /// lowercase string. An error is returned if the subtype is not [`BinarySubtype::Uuid`] or the
+    /// bytes are not 16 bytes long.
+    pub fn to_uuid_str(&self) -> Result<String> {
+        self.to_uuid().map(|uuid| uuid.to_string())
+    }
 }
 
 macro_rules! trait_impls {